@@ -0,0 +1,65 @@
+//! Runtime ABI/bytecode loading, as an alternative to the compile-time
+//! `sol!` bindings in [`crate::contract`].
+//!
+//! [`contract::load_bytecode`](crate::contract::load_bytecode) hardcodes a
+//! single Foundry artifact path, and every call is encoded through a fixed
+//! ABI baked in at compile time. That's fine for the contract version this
+//! binary shipped against, but targeting an older deployment or a revision
+//! that hasn't been compiled into this binary needs the ABI and bytecode to
+//! come from disk instead. [`load`] reads a Foundry-shaped artifact
+//! (`{"abi": [...], "bytecode": {"object": "0x..."}}`) named
+//! `<dir>/<name>.<version>.json`, and [`encode_call`] encodes a function
+//! call against its dynamic [`JsonAbi`], looking the function up by name.
+
+use crate::Result;
+use alloy::dyn_abi::{DynSolValue, JsonAbiExt};
+use alloy::json_abi::JsonAbi;
+use alloy::primitives::Bytes;
+
+/// A loaded `{abi, bytecode}` pair for one contract version
+pub struct Artifact {
+    pub abi: JsonAbi,
+    pub bytecode: Bytes,
+}
+
+/// Load `<dir>/<name>.<version>.json`, e.g. `artifacts/CryptoHeir.v2.json`
+pub fn load(dir: &str, name: &str, version: &str) -> Result<Artifact> {
+    let path = format!("{}/{}.{}.json", dir, name, version);
+    let artifact_str = std::fs::read_to_string(&path).map_err(|e| {
+        eyre::eyre!("Failed to read contract artifact at {}: {}", path, e)
+    })?;
+    let artifact: serde_json::Value = serde_json::from_str(&artifact_str)?;
+
+    let abi: JsonAbi = serde_json::from_value(artifact["abi"].clone())
+        .map_err(|e| eyre::eyre!("Failed to parse ABI in {}: {}", path, e))?;
+
+    let bytecode_str = artifact["bytecode"]["object"]
+        .as_str()
+        .ok_or_else(|| eyre::eyre!("Bytecode not found in artifact {}", path))?;
+    let bytecode: Bytes = bytecode_str.parse()?;
+
+    Ok(Artifact { abi, bytecode })
+}
+
+/// Encode a call to `function` with `args` against `artifact`'s dynamic ABI,
+/// using the first overload of that name taking `args.len()` arguments and
+/// prefixing its selector, so the CLI can target an older deployed
+/// contract or a new revision without recompiling the static `sol!`
+/// bindings in [`crate::contract`].
+pub fn encode_call(artifact: &Artifact, function: &str, args: &[DynSolValue]) -> Result<Bytes> {
+    let overloads = artifact
+        .abi
+        .function(function)
+        .ok_or_else(|| eyre::eyre!("Function {} not found in artifact ABI", function))?;
+
+    let f = overloads
+        .iter()
+        .find(|f| f.inputs.len() == args.len())
+        .ok_or_else(|| {
+            eyre::eyre!("No overload of {} takes {} argument(s)", function, args.len())
+        })?;
+
+    Ok(f.abi_encode_input(args)
+        .map_err(|e| eyre::eyre!("Failed to encode {}: {}", function, e))?
+        .into())
+}
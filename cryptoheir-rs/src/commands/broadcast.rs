@@ -1,26 +1,39 @@
 //! Broadcast command - broadcasts signed transactions (requires network access)
 
 use crate::{network, qr, types::*, Result};
+use alloy::primitives::B256;
+use alloy::providers::Provider;
 use tracing::{info, warn};
 
+#[allow(clippy::too_many_arguments)]
 pub async fn execute(
     input: String,
     network: Option<String>,
     rpc_url: Option<String>,
     output: String,
     qr_input: Option<String>,
+    expect_transfer: bool,
+    expect_event: Option<String>,
+    confirmations: u64,
 ) -> Result<()> {
     info!("Loading signed transaction...");
 
     // Load signed tx from file or QR code
     let signed_tx_json = if let Some(qr_file) = qr_input {
         info!("Scanning QR code from {}...", qr_file);
-        qr::scan_qr(&qr_file)?
+        qr::scan_qr_path(&qr_file)?
     } else {
         std::fs::read_to_string(&input)?
     };
 
-    let signed_tx: SignedTx = serde_json::from_str(&signed_tx_json)?;
+    // A batch prepared/signed in one air-gapped session is a JSON array of
+    // `SignedTx` instead of a single object; broadcast each in order,
+    // waiting for its receipt before moving to the next.
+    let parsed: serde_json::Value = serde_json::from_str(&signed_tx_json)?;
+    if parsed.is_array() {
+        return execute_batch(parsed, network, rpc_url, output, expect_transfer, expect_event, confirmations).await;
+    }
+    let signed_tx: SignedTx = serde_json::from_value(parsed)?;
 
     // Load configuration
     let config = Config::load()?;
@@ -57,26 +70,7 @@ pub async fn execute(
         signed_tx.metadata.network.name, chain_id
     );
 
-    // Check if transaction was already broadcast
-    let existing_tx = network::get_transaction(&client, signed_tx.tx_hash).await;
-    if existing_tx.is_ok() {
-        warn!("Transaction {} appears to already be broadcast", signed_tx.tx_hash);
-        println!("\n⚠ Transaction already broadcast: {}", signed_tx.tx_hash);
-        println!("Waiting for confirmation...");
-    } else {
-        // Broadcast the transaction
-        info!("Broadcasting transaction {}...", signed_tx.tx_hash);
-        println!("\nBroadcasting transaction...");
-        println!("  TX Hash: {}", signed_tx.tx_hash);
-
-        network::broadcast_transaction(&client, &signed_tx.signed_transaction).await?;
-
-        println!("✓ Transaction broadcast successfully!");
-    }
-
-    // Wait for receipt
-    println!("\nWaiting for confirmation...");
-    let receipt = network::wait_for_receipt(&client, signed_tx.tx_hash).await?;
+    let receipt = broadcast_and_confirm(&client, &signed_tx, expect_transfer, expect_event.as_deref(), confirmations).await?;
 
     // Save receipt
     let receipt_json = serde_json::to_string_pretty(&receipt)?;
@@ -84,9 +78,17 @@ pub async fn execute(
 
     println!("\n✓ Transaction confirmed!");
     println!("  Block: {}", receipt.block_number);
-    println!("  Gas Used: {}", receipt.gas_used);
+    println!("  Type: {}", receipt.tx_type);
+    println!("  Gas Used: {} (cumulative: {})", receipt.gas_used, receipt.cumulative_gas_used);
+    println!("  Effective Gas Price: {} wei", receipt.effective_gas_price);
     println!("  Status: {}", if receipt.status == 1 { "Success" } else { "Failed" });
 
+    if let Some(reason) = &receipt.revert_reason {
+        println!("  Revert Reason: {}", reason);
+    } else if receipt.status != 1 {
+        println!("  Revert Reason: could not be decoded");
+    }
+
     if let Some(contract_addr) = receipt.contract_address {
         println!("  Contract Address: {}", contract_addr);
     }
@@ -95,3 +97,143 @@ pub async fn execute(
 
     Ok(())
 }
+
+/// Broadcast every `SignedTx` in a batch, in array order (the same order
+/// `prepare`/`sign` assigned consecutive nonces), waiting for each receipt
+/// before sending the next and aborting the remainder the first time one
+/// fails to confirm or doesn't produce its expected event.
+#[allow(clippy::too_many_arguments)]
+async fn execute_batch(
+    batch: serde_json::Value,
+    network: Option<String>,
+    rpc_url: Option<String>,
+    output: String,
+    expect_transfer: bool,
+    expect_event: Option<String>,
+    confirmations: u64,
+) -> Result<()> {
+    let batch: Vec<SignedTx> = serde_json::from_value(batch)?;
+    if batch.is_empty() {
+        return Err(eyre::eyre!("Batch contains no transactions"));
+    }
+
+    let config = Config::load()?;
+    let first = &batch[0];
+
+    let rpc_url = rpc_url
+        .or(config.rpc_url)
+        .or_else(|| first.metadata.network.rpc_url.clone())
+        .or_else(|| {
+            network::get_rpc_url(
+                network.as_deref().unwrap_or("sepolia"),
+                config.infura_api_key.as_deref(),
+            )
+        })
+        .ok_or_else(|| eyre::eyre!("No RPC URL provided or configured"))?;
+
+    info!("Connecting to network via {}", rpc_url);
+    let client = network::create_client(&rpc_url).await?;
+
+    let chain_id = network::get_chain_id(&client).await?;
+    if chain_id != first.metadata.network.chain_id {
+        return Err(eyre::eyre!(
+            "Chain ID mismatch! Expected {}, but connected to {}",
+            first.metadata.network.chain_id,
+            chain_id
+        ));
+    }
+    info!("Connected to {} (chain ID: {})", first.metadata.network.name, chain_id);
+
+    let mut receipts = Vec::with_capacity(batch.len());
+    for (index, signed_tx) in batch.iter().enumerate() {
+        println!(
+            "\nBroadcasting item {} of {}: {}",
+            index + 1,
+            batch.len(),
+            signed_tx.tx_hash
+        );
+
+        match broadcast_and_confirm(&client, signed_tx, expect_transfer, expect_event.as_deref(), confirmations).await {
+            Ok(receipt) => {
+                println!(
+                    "  ✓ Confirmed in block {} (status: {})",
+                    receipt.block_number,
+                    if receipt.status == 1 { "success" } else { "failed" }
+                );
+                receipts.push(receipt);
+            }
+            Err(e) => {
+                let json = serde_json::to_string_pretty(&receipts)?;
+                std::fs::write(&output, &json)?;
+                return Err(eyre::eyre!(
+                    "Batch item {} ({}) failed: {}. Aborting remaining {} transaction(s); {} receipt(s) saved to {}.",
+                    index,
+                    signed_tx.tx_hash,
+                    e,
+                    batch.len() - index - 1,
+                    receipts.len(),
+                    output
+                ));
+            }
+        }
+    }
+
+    let json = serde_json::to_string_pretty(&receipts)?;
+    std::fs::write(&output, &json)?;
+
+    println!("\n✓ {} transaction(s) broadcast and confirmed in order!", receipts.len());
+    println!("  Receipts saved to: {}", output);
+
+    Ok(())
+}
+
+/// Broadcast one `SignedTx` (skipping the send if it's already on chain),
+/// wait for its receipt, and run any requested event assertions. Shared by
+/// the single-transaction and batch paths.
+async fn broadcast_and_confirm(
+    client: &impl Provider,
+    signed_tx: &SignedTx,
+    expect_transfer: bool,
+    expect_event: Option<&str>,
+    confirmations: u64,
+) -> Result<TxReceipt> {
+    // Check if transaction was already broadcast
+    let existing_tx = network::get_transaction(client, signed_tx.tx_hash).await;
+    if existing_tx.is_ok() {
+        warn!("Transaction {} appears to already be broadcast", signed_tx.tx_hash);
+        println!("⚠ Transaction already broadcast: {}", signed_tx.tx_hash);
+        println!("Waiting for confirmation...");
+    } else {
+        info!("Broadcasting transaction {}...", signed_tx.tx_hash);
+        println!("  TX Hash: {}", signed_tx.tx_hash);
+
+        network::broadcast_transaction(client, &signed_tx.signed_transaction).await?;
+
+        println!("  ✓ Broadcast successfully, waiting for confirmation...");
+    }
+
+    if confirmations > 1 {
+        println!("  Waiting for {} confirmation(s)...", confirmations);
+    }
+    let receipt = network::wait_for_receipt(client, signed_tx.tx_hash, confirmations).await?;
+
+    // Confirm the transaction didn't just avoid reverting, but actually did
+    // what it claimed to. A missing or mismatched event fails the command
+    // even when receipt.status() is a success.
+    if expect_transfer {
+        let expected = signed_tx.metadata.expected_transfer.as_ref().ok_or_else(|| {
+            eyre::eyre!("--expect-transfer given but the prepared transaction recorded no expected transfer")
+        })?;
+        network::assert_transfer_event(&receipt, expected)?;
+        println!("  ✓ Expected Transfer event confirmed");
+    }
+    if let Some(topic0_hex) = expect_event {
+        let topic0: B256 = topic0_hex
+            .parse()
+            .map_err(|e| eyre::eyre!("Invalid --expect-event topic0 {}: {}", topic0_hex, e))?;
+        network::assert_event_topic(&receipt, topic0)?;
+        println!("  ✓ Expected event confirmed");
+    }
+
+    Ok(receipt)
+}
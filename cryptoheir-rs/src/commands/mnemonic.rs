@@ -1,9 +1,52 @@
 //! Mnemonic commands - generate and derive keys from BIP39 mnemonic phrases
 
-use crate::Result;
+use crate::{vault, Result};
 use alloy_signer_local::MnemonicBuilder;
+use clap::Subcommand;
 use tracing::{info, warn};
 
+/// One action on a BIP-39 mnemonic, either in plaintext (`generate`,
+/// `derive`) or as a passphrase-sealed vault file (`encrypt`, `decrypt`)
+#[derive(Subcommand, Debug)]
+pub enum MnemonicAction {
+    /// Generate a new 24-word mnemonic phrase
+    Generate {
+        /// Also derive and display the first Ethereum account
+        #[arg(long)]
+        show_keys: bool,
+    },
+
+    /// Derive an Ethereum private key from a mnemonic phrase (prompts for it)
+    Derive {
+        /// Account index to derive (default 0)
+        #[arg(long)]
+        index: Option<u32>,
+    },
+
+    /// Seal a mnemonic phrase into a passphrase-encrypted vault file
+    Encrypt {
+        /// Output file path for the vault
+        #[arg(short, long, default_value = "mnemonic-vault.json")]
+        output: String,
+    },
+
+    /// Decrypt a vault file and print the mnemonic phrase
+    Decrypt {
+        /// Input file path for the vault
+        #[arg(short, long, default_value = "mnemonic-vault.json")]
+        input: String,
+    },
+}
+
+pub async fn execute(action: MnemonicAction) -> Result<()> {
+    match action {
+        MnemonicAction::Generate { show_keys } => generate(show_keys).await,
+        MnemonicAction::Derive { index } => derive(index).await,
+        MnemonicAction::Encrypt { output } => encrypt(output).await,
+        MnemonicAction::Decrypt { input } => decrypt(input).await,
+    }
+}
+
 /// Generate a new 24-word BIP39 mnemonic phrase
 pub async fn generate(show_keys: bool) -> Result<()> {
     info!("Generating 24-word BIP39 mnemonic phrase...");
@@ -115,3 +158,73 @@ pub async fn derive(index: Option<u32>) -> Result<()> {
 
     Ok(())
 }
+
+/// Seal a mnemonic phrase (read from stdin, visible, like `derive`) into a
+/// passphrase-encrypted vault file, so the seed never has to sit in
+/// plaintext on a shared machine.
+async fn encrypt(output: String) -> Result<()> {
+    println!("\n{}", "=".repeat(70));
+    println!("  Encrypt Mnemonic Phrase into a Vault");
+    println!("{}", "=".repeat(70));
+    println!("\nEnter your 12 or 24-word mnemonic phrase:");
+    println!("(Input will be visible - use in a private location)");
+    print!("> ");
+
+    use std::io::Write;
+    std::io::stdout().flush()?;
+
+    let mut mnemonic_phrase = String::new();
+    std::io::stdin()
+        .read_line(&mut mnemonic_phrase)
+        .map_err(|e| eyre::eyre!("Failed to read mnemonic: {}", e))?;
+    let mnemonic_phrase = mnemonic_phrase.trim();
+
+    let word_count = mnemonic_phrase.split_whitespace().count();
+    if word_count != 12 && word_count != 24 {
+        return Err(eyre::eyre!(
+            "Invalid mnemonic: expected 12 or 24 words, got {}",
+            word_count
+        ));
+    }
+
+    let passphrase = rpassword::prompt_password("Vault passphrase: ")
+        .map_err(|e| eyre::eyre!("Failed to read passphrase: {}", e))?;
+    let confirm = rpassword::prompt_password("Confirm passphrase: ")
+        .map_err(|e| eyre::eyre!("Failed to read passphrase: {}", e))?;
+    if passphrase != confirm {
+        return Err(eyre::eyre!("Passphrases did not match"));
+    }
+
+    info!("Sealing {}-word mnemonic with Argon2id + ChaCha20-Poly1305...", word_count);
+    let sealed = vault::seal(mnemonic_phrase, &passphrase)?;
+    let json = serde_json::to_string_pretty(&sealed)?;
+    std::fs::write(&output, &json)?;
+
+    println!("\n✓ Mnemonic sealed to {}", output);
+    warn!("The plaintext mnemonic was never written to disk, only the sealed vault was.");
+    warn!("Losing the passphrase makes the vault unrecoverable; back it up separately.");
+
+    Ok(())
+}
+
+/// Decrypt a vault file and print the plaintext mnemonic.
+async fn decrypt(input: String) -> Result<()> {
+    let json = std::fs::read_to_string(&input)
+        .map_err(|e| eyre::eyre!("Failed to read vault {}: {}", input, e))?;
+    let sealed: vault::VaultFile = serde_json::from_str(&json)?;
+
+    let passphrase = rpassword::prompt_password(format!("Passphrase for {}: ", input))
+        .map_err(|e| eyre::eyre!("Failed to read passphrase: {}", e))?;
+
+    let mnemonic_phrase = vault::open(&sealed, &passphrase)?;
+
+    println!("\n{}", "=".repeat(70));
+    println!("  Decrypted Mnemonic Phrase");
+    println!("{}", "=".repeat(70));
+    println!("\n{}\n", mnemonic_phrase);
+    println!("{}", "=".repeat(70));
+
+    warn!("Anyone with this phrase can access your funds. Never share it.");
+
+    Ok(())
+}
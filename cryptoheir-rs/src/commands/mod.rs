@@ -3,4 +3,6 @@
 pub mod broadcast;
 pub mod mnemonic;
 pub mod prepare;
+pub mod scan;
 pub mod sign;
+pub mod verify;
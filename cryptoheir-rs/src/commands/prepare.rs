@@ -1,17 +1,70 @@
 //! Prepare command - creates unsigned transactions (requires network access)
 
-use crate::{contract, network, qr, types::*, Result};
-use alloy::primitives::{Address, U256};
-use clap::Subcommand;
+use crate::{artifact, contract, deploy, network, qr, types::*, Result};
+use alloy::dyn_abi::DynSolValue;
+use alloy::primitives::{Address, Bytes, B256, U256};
+use clap::{Subcommand, ValueEnum};
+use serde::{Deserialize, Serialize};
 use tracing::info;
 
 // Type alias for the RPC client type
 type RpcClient = alloy::providers::RootProvider<alloy::transports::http::Http<alloy::transports::http::Client>>;
 
-#[derive(Subcommand, Debug, Clone)]
+/// Fee tier, selecting which `eth_feeHistory` reward percentile to use as
+/// the priority fee
+#[derive(ValueEnum, Debug, Clone, Copy, Default)]
+pub enum FeeSpeed {
+    Slow,
+    #[default]
+    Standard,
+    Fast,
+}
+
+impl FeeSpeed {
+    /// The reward percentile to request from `eth_feeHistory`
+    pub fn percentile(self) -> f64 {
+        match self {
+            FeeSpeed::Slow => 25.0,
+            FeeSpeed::Standard => 50.0,
+            FeeSpeed::Fast => 75.0,
+        }
+    }
+}
+
+/// Fee-estimation knobs threaded down to [`network::get_gas_prices`]
+#[derive(Debug, Clone, Copy)]
+pub struct FeeOptions {
+    pub speed: FeeSpeed,
+    /// Multiplier applied to the latest base fee before adding the priority
+    /// fee, to tolerate a few base-fee increases before broadcast
+    pub multiplier: f64,
+    /// Floor (in wei) the suggested priority fee is never below, even if
+    /// recent blocks reported near-zero tips
+    pub priority_fee_floor: U256,
+}
+
+/// One action to prepare, either from the CLI subcommand or from a batch
+/// manifest file (see [`execute_batch`])
+#[derive(Subcommand, Debug, Clone, Serialize, Deserialize)]
 pub enum Operation {
     /// Deploy a new CryptoHeir contract
-    Deploy,
+    Deploy {
+        /// Deploy deterministically via CREATE2 with this salt instead of
+        /// plain CREATE, giving the same address on every chain
+        #[arg(long)]
+        salt: Option<B256>,
+
+        /// CREATE2 deployer/factory contract to deploy through (only used
+        /// with --salt). Defaults to Arachnid's widely-deployed deterministic
+        /// deployment proxy.
+        #[arg(long)]
+        deployer: Option<Address>,
+
+        /// ABI-encoded constructor arguments (hex), appended to the creation
+        /// bytecode and folded into the predicted CREATE2 address
+        #[arg(long)]
+        constructor_args: Option<Bytes>,
+    },
 
     /// Deposit funds into an inheritance
     Deposit {
@@ -92,14 +145,41 @@ pub enum Operation {
     },
 }
 
+#[allow(clippy::too_many_arguments)]
 pub async fn execute(
     operation: Operation,
     network: Option<String>,
     rpc_url: Option<String>,
     output: String,
     generate_qr: bool,
+    fee_speed: FeeSpeed,
+    fee_multiplier: f64,
+    priority_fee_floor_gwei: u64,
+    access_list: bool,
+    verify_rpc: Vec<String>,
+    allow_contract_sender: bool,
+    nonce_override: Option<u64>,
+    artifact_dir: Option<String>,
+    contract_version: Option<String>,
 ) -> Result<()> {
+    // A runtime artifact (ABI + bytecode loaded from disk) takes over
+    // encoding from the binary's compiled-in `sol!` ABI when both a
+    // directory and a version are given, so the CLI can target a contract
+    // revision this binary was never built against
+    let artifact = match (&artifact_dir, &contract_version) {
+        (Some(dir), Some(version)) => {
+            info!("Loading artifact {} version {} from {}", "CryptoHeir", version, dir);
+            Some(artifact::load(dir, "CryptoHeir", version)?)
+        }
+        _ => None,
+    };
+
     info!("Preparing transaction...");
+    let fee_options = FeeOptions {
+        speed: fee_speed,
+        multiplier: fee_multiplier,
+        priority_fee_floor: U256::from(priority_fee_floor_gwei) * U256::from(1_000_000_000u64),
+    };
 
     // Load configuration
     let config = Config::load()?;
@@ -134,14 +214,122 @@ pub async fn execute(
         network_name, chain_id
     );
 
-    // Get nonce
-    let nonce = network::get_nonce(&client, signer_address).await?;
-    info!("Nonce: {}", nonce);
+    // Get nonce, or use the caller's override to prepare a same-nonce
+    // replacement for a transaction stuck in the mempool
+    let nonce = match nonce_override {
+        Some(nonce) => {
+            info!("Using overridden nonce: {} (replacement transaction)", nonce);
+            nonce
+        }
+        None => {
+            let nonce = network::get_nonce(&client, signer_address).await?;
+            info!("Nonce: {}", nonce);
+            nonce
+        }
+    };
+
+    // EIP-3607: refuse to build a transaction from an address that has
+    // deployed code unless explicitly overridden. A mistyped or contract
+    // address here would only fail at broadcast time, after the whole
+    // offline signing round-trip.
+    if !allow_contract_sender {
+        let code = network::get_code(&client, signer_address).await?;
+        if !code.is_empty() {
+            return Err(eyre::eyre!(
+                "Signer address {} has deployed code; refusing to prepare a transaction from it \
+                 (EIP-3607). Pass --allow-contract-sender if this is intentional.",
+                signer_address
+            ));
+        }
+    }
 
     // Prepare transaction based on operation
-    let tx_params = match operation {
-        Operation::Deploy => {
-            prepare_deploy(&client, signer_address, nonce, chain_id, &network_name).await?
+    let mut tx_params = prepare_operation(
+        &client,
+        signer_address,
+        nonce,
+        chain_id,
+        &network_name,
+        &config,
+        operation,
+        fee_options,
+        access_list,
+        artifact.as_ref(),
+    )
+    .await?;
+
+    // Trust-minimize: if independent RPC endpoints were given, refuse to
+    // emit this TxParams unless they all agree on chain ID, nonce, and (for
+    // a contract call) bytecode hash
+    if !verify_rpc.is_empty() {
+        let contract = tx_params.transaction.common().to;
+        let consulted = network::cross_check_rpc(
+            &client,
+            &rpc_url,
+            &verify_rpc,
+            chain_id,
+            nonce,
+            signer_address,
+            contract,
+        )
+        .await?;
+        info!("Cross-checked {} RPC endpoint(s)", consulted.len());
+        tx_params.metadata.verified_rpc_endpoints = Some(consulted);
+    }
+
+    // Save to file
+    let json = serde_json::to_string_pretty(&tx_params)?;
+    std::fs::write(&output, &json)?;
+
+    info!("Transaction parameters saved to {}", output);
+    println!("\n✓ Transaction prepared successfully!");
+    println!("  Output: {}", output);
+    println!("  Network: {} (chain ID: {})", network_name, chain_id);
+    println!("  Estimated cost: {} ETH", tx_params.metadata.estimated_cost);
+
+    // Generate QR code if requested
+    if generate_qr {
+        info!("Generating QR code...");
+        qr::display_qr(&json)?;
+    }
+
+    println!("\nNext step: Transfer {} to offline machine and run:", output);
+    println!("  cryptoheir-rs sign -i {}", output);
+
+    Ok(())
+}
+
+/// Dispatch a single `Operation` to its `prepare_*` function. Shared by the
+/// single-shot `execute` and the nonce-sequenced `execute_batch`.
+#[allow(clippy::too_many_arguments)]
+async fn prepare_operation(
+    client: &RpcClient,
+    signer_address: Address,
+    nonce: u64,
+    chain_id: u64,
+    network_name: &str,
+    config: &Config,
+    operation: Operation,
+    fee_options: FeeOptions,
+    access_list: bool,
+    artifact: Option<&artifact::Artifact>,
+) -> Result<TxParams> {
+    match operation {
+        Operation::Deploy { salt, deployer, constructor_args } => {
+            prepare_deploy(
+                client,
+                signer_address,
+                nonce,
+                chain_id,
+                network_name,
+                fee_options,
+                access_list,
+                salt,
+                deployer,
+                constructor_args,
+                artifact,
+            )
+            .await
         }
         Operation::Deposit {
             beneficiary,
@@ -154,48 +342,51 @@ pub async fn execute(
                 eyre::eyre!("Contract address required (use --contract or set CONTRACT_ADDRESS)")
             })?;
             prepare_deposit(
-                &client,
+                client,
                 signer_address,
                 nonce,
                 chain_id,
-                &network_name,
+                network_name,
                 contract_addr,
                 beneficiary,
                 amount,
                 deadline,
                 token,
+                fee_options,
+                access_list,
+                artifact,
             )
-            .await?
+            .await
         }
         Operation::Claim { id, contract } => {
             let contract_addr = contract.or(config.contract_address).ok_or_else(|| {
                 eyre::eyre!("Contract address required (use --contract or set CONTRACT_ADDRESS)")
             })?;
             prepare_claim(
-                &client,
+                client,
                 signer_address,
                 nonce,
                 chain_id,
-                &network_name,
+                network_name,
                 contract_addr,
                 id,
             )
-            .await?
+            .await
         }
         Operation::Reclaim { id, contract } => {
             let contract_addr = contract.or(config.contract_address).ok_or_else(|| {
                 eyre::eyre!("Contract address required (use --contract or set CONTRACT_ADDRESS)")
             })?;
             prepare_reclaim(
-                &client,
+                client,
                 signer_address,
                 nonce,
                 chain_id,
-                &network_name,
+                network_name,
                 contract_addr,
                 id,
             )
-            .await?
+            .await
         }
         Operation::ExtendDeadline {
             id,
@@ -206,16 +397,16 @@ pub async fn execute(
                 eyre::eyre!("Contract address required (use --contract or set CONTRACT_ADDRESS)")
             })?;
             prepare_extend_deadline(
-                &client,
+                client,
                 signer_address,
                 nonce,
                 chain_id,
-                &network_name,
+                network_name,
                 contract_addr,
                 id,
                 new_deadline,
             )
-            .await?
+            .await
         }
         Operation::TransferFeeCollector {
             new_collector,
@@ -225,43 +416,152 @@ pub async fn execute(
                 eyre::eyre!("Contract address required (use --contract or set CONTRACT_ADDRESS)")
             })?;
             prepare_transfer_fee_collector(
-                &client,
+                client,
                 signer_address,
                 nonce,
                 chain_id,
-                &network_name,
+                network_name,
                 contract_addr,
                 new_collector,
             )
-            .await?
+            .await
         }
         Operation::AcceptFeeCollector { contract } => {
             let contract_addr = contract.or(config.contract_address).ok_or_else(|| {
                 eyre::eyre!("Contract address required (use --contract or set CONTRACT_ADDRESS)")
             })?;
             prepare_accept_fee_collector(
-                &client,
+                client,
                 signer_address,
                 nonce,
                 chain_id,
-                &network_name,
+                network_name,
                 contract_addr,
             )
-            .await?
+            .await
         }
+    }
+}
+
+/// Prepare a batch of operations in one offline signing pass.
+///
+/// Reads a JSON manifest (an array of [`Operation`], externally tagged by
+/// variant name, e.g. `[{"Deposit": {"beneficiary": "0x..", "amount": "1.5",
+/// "deadline": 1999999999, "token": null, "contract": null}}, {"ExtendDeadline":
+/// {...}}]`), fetches the starting nonce once, and assigns each item the next
+/// nonce in sequence like a nonce manager. Gas and fees are re-estimated per
+/// item against the node's latest state as each item is prepared; the items
+/// are not pinned to one block. The result is written as a JSON array of
+/// `TxParams` that `sign` can iterate over.
+#[allow(clippy::too_many_arguments)]
+pub async fn execute_batch(
+    manifest: String,
+    network: Option<String>,
+    rpc_url: Option<String>,
+    output: String,
+    generate_qr: bool,
+    fee_speed: FeeSpeed,
+    fee_multiplier: f64,
+    priority_fee_floor_gwei: u64,
+    access_list: bool,
+    allow_contract_sender: bool,
+) -> Result<()> {
+    info!("Preparing batch transaction...");
+    let fee_options = FeeOptions {
+        speed: fee_speed,
+        multiplier: fee_multiplier,
+        priority_fee_floor: U256::from(priority_fee_floor_gwei) * U256::from(1_000_000_000u64),
     };
 
-    // Save to file
-    let json = serde_json::to_string_pretty(&tx_params)?;
+    let manifest_json = std::fs::read_to_string(&manifest)?;
+    let operations: Vec<Operation> = serde_json::from_str(&manifest_json)?;
+    if operations.is_empty() {
+        return Err(eyre::eyre!("Batch manifest {} contains no operations", manifest));
+    }
+
+    // Load configuration
+    let config = Config::load()?;
+
+    // Determine RPC URL
+    let rpc_url = rpc_url
+        .or(config.rpc_url.clone())
+        .or_else(|| {
+            network::get_rpc_url(
+                network.as_deref().unwrap_or("sepolia"),
+                config.infura_api_key.as_deref(),
+            )
+        })
+        .ok_or_else(|| eyre::eyre!("No RPC URL provided or configured"))?;
+
+    info!("Connecting to network via {}", rpc_url);
+
+    // Get signer address
+    let signer_address = config
+        .signer_address
+        .ok_or_else(|| eyre::eyre!("SIGNER_ADDRESS not set in environment"))?;
+
+    // Create RPC client
+    let client = network::create_client(&rpc_url).await?;
+
+    // Get network info
+    let chain_id = network::get_chain_id(&client).await?;
+    let network_name = network.unwrap_or_else(|| "custom".to_string());
+
+    info!("Connected to {} (chain ID: {})", network_name, chain_id);
+
+    // EIP-3607: refuse to build transactions from an address with deployed
+    // code unless explicitly overridden; see `execute` for the single-shot
+    // version of this check.
+    if !allow_contract_sender {
+        let code = network::get_code(&client, signer_address).await?;
+        if !code.is_empty() {
+            return Err(eyre::eyre!(
+                "Signer address {} has deployed code; refusing to prepare transactions from it \
+                 (EIP-3607). Pass --allow-contract-sender if this is intentional.",
+                signer_address
+            ));
+        }
+    }
+
+    // Fetch the starting nonce once; every subsequent item takes the next one
+    let starting_nonce = network::get_nonce(&client, signer_address).await?;
+    info!(
+        "Starting nonce: {} ({} operation(s) in batch)",
+        starting_nonce,
+        operations.len()
+    );
+
+    let mut batch = Vec::with_capacity(operations.len());
+    for (index, operation) in operations.into_iter().enumerate() {
+        let nonce = starting_nonce + index as u64;
+        info!("Preparing batch item {} at nonce {}...", index, nonce);
+        // Batch manifests don't carry per-operation artifact info; a batch
+        // item always targets the binary's compiled-in ABI/bytecode
+        let tx_params = prepare_operation(
+            &client,
+            signer_address,
+            nonce,
+            chain_id,
+            &network_name,
+            &config,
+            operation,
+            fee_options,
+            access_list,
+            None,
+        )
+        .await?;
+        batch.push(tx_params);
+    }
+
+    let json = serde_json::to_string_pretty(&batch)?;
     std::fs::write(&output, &json)?;
 
-    info!("Transaction parameters saved to {}", output);
-    println!("\n✓ Transaction prepared successfully!");
+    info!("Batch transaction parameters saved to {}", output);
+    println!("\n✓ Batch of {} transaction(s) prepared successfully!", batch.len());
     println!("  Output: {}", output);
     println!("  Network: {} (chain ID: {})", network_name, chain_id);
-    println!("  Estimated cost: {} ETH", tx_params.metadata.estimated_cost);
+    println!("  Nonces: {}..{}", starting_nonce, starting_nonce + batch.len() as u64 - 1);
 
-    // Generate QR code if requested
     if generate_qr {
         info!("Generating QR code...");
         qr::display_qr(&json)?;
@@ -273,62 +573,176 @@ pub async fn execute(
     Ok(())
 }
 
+/// Build a `TypedTransaction` from its common fields, picking EIP-1559
+/// (type 2) when fee-history gas prices are available, EIP-2930 (type 1)
+/// when only a legacy gas price is available but an access list was
+/// requested, and legacy (type 0) otherwise
+fn build_typed_transaction(
+    from: Address,
+    to: Option<Address>,
+    data: Bytes,
+    nonce: u64,
+    chain_id: u64,
+    gas_limit: U256,
+    value: Option<U256>,
+    max_fee_per_gas: Option<U256>,
+    max_priority_fee_per_gas: Option<U256>,
+    gas_price: Option<U256>,
+    access_list: Vec<AccessListItem>,
+) -> TypedTransaction {
+    let common = CommonTxFields {
+        from,
+        to,
+        data,
+        nonce,
+        chain_id,
+        gas_limit,
+        value,
+    };
+
+    match (max_fee_per_gas, max_priority_fee_per_gas) {
+        (Some(max_fee_per_gas), Some(max_priority_fee_per_gas)) => TypedTransaction::Eip1559 {
+            common,
+            max_fee_per_gas,
+            max_priority_fee_per_gas,
+            access_list,
+        },
+        _ if !access_list.is_empty() => TypedTransaction::Eip2930 {
+            common,
+            gas_price: gas_price.unwrap_or_default(),
+            access_list,
+        },
+        _ => TypedTransaction::Legacy {
+            common,
+            gas_price: gas_price.unwrap_or_default(),
+        },
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
 async fn prepare_deploy(
     client: &RpcClient,
     from: Address,
     nonce: u64,
     chain_id: u64,
     network_name: &str,
+    fee_options: FeeOptions,
+    use_access_list: bool,
+    salt: Option<B256>,
+    deployer: Option<Address>,
+    constructor_args: Option<Bytes>,
+    artifact: Option<&artifact::Artifact>,
 ) -> Result<TxParams> {
     info!("Preparing contract deployment...");
 
-    // Load contract bytecode
-    let bytecode = contract::load_bytecode()?;
-    let data = bytecode.into();
+    // Load contract bytecode, plus any ABI-encoded constructor arguments.
+    // A runtime artifact's bytecode takes over from the compiled-in Foundry
+    // path when one was loaded (e.g. targeting a contract revision this
+    // binary was never built against).
+    let constructor_args = constructor_args.unwrap_or_default();
+    let init_code = match artifact {
+        Some(artifact) => {
+            let mut code = artifact.bytecode.to_vec();
+            code.extend_from_slice(&constructor_args);
+            Bytes::from(code)
+        }
+        None => deploy::init_code(&constructor_args)?,
+    };
+
+    // With a --salt, deploy deterministically through a CREATE2 deployer
+    // proxy instead of plain CREATE: calldata becomes salt ++ init_code, the
+    // recipient becomes the deployer, and the resulting address is knowable
+    // up front.
+    let (to, data, predicted_contract_address): (Option<Address>, Bytes, Option<Address>) =
+        if let Some(salt) = salt {
+            let deployer = deployer.unwrap_or(contract::CREATE2_DEPLOYER);
+            let predicted = contract::predict_create2_address(deployer, salt, &init_code);
+
+            // Re-running a deployment that already landed is a no-op at best
+            // and a wasted-gas mistake at worst; refuse so the same salt is
+            // safe to reuse across networks.
+            deploy::assert_not_deployed(client, predicted).await?;
+
+            let mut calldata = salt.to_vec();
+            calldata.extend_from_slice(&init_code);
+
+            info!(
+                "CREATE2 salt {} via deployer {} predicts address {}",
+                salt, deployer, predicted
+            );
+
+            (Some(deployer), Bytes::from(calldata), Some(predicted))
+        } else {
+            (None, init_code, None)
+        };
 
     // Estimate gas
-    let gas_limit = network::estimate_gas(client, from, None, &data, None).await?;
+    let gas_limit = network::estimate_gas(client, from, to, &data, None).await?;
 
     // Get gas prices
-    let (max_fee_per_gas, max_priority_fee_per_gas, gas_price) =
-        network::get_gas_prices(client).await?;
-
-    // Determine transaction type and set appropriate gas fields
-    let (tx_type, final_max_fee, final_priority_fee, final_gas_price) =
-        if max_fee_per_gas.is_some() {
-            // EIP-1559 transaction (type 2)
-            (2, max_fee_per_gas, max_priority_fee_per_gas, None)
-        } else {
-            // Legacy transaction (type 0)
-            (0, None, None, gas_price)
-        };
+    let (max_fee_per_gas, max_priority_fee_per_gas, gas_price) = network::get_gas_prices(
+        client,
+        fee_options.speed.percentile(),
+        fee_options.multiplier,
+        fee_options.priority_fee_floor,
+    )
+    .await?;
+
+    // Optionally ask the node for an access list to cut execution gas
+    let access_list = if use_access_list {
+        network::create_access_list(client, from, to, &data, None)
+            .await
+            .map(|(list, _refined_gas)| list)
+            .unwrap_or_default()
+    } else {
+        Vec::new()
+    };
+
+    // Cross-check against eth_feeHistory so a reviewer can sanity-check fees
+    let suggested_fees = network::suggest_fees(client).await.ok().map(
+        |(max_fee_per_gas, max_priority_fee_per_gas)| SuggestedFees {
+            max_fee_per_gas,
+            max_priority_fee_per_gas,
+        },
+    );
 
     // Calculate estimated cost
-    let estimated_cost = if let Some(max_fee) = final_max_fee {
+    let estimated_cost = if let Some(max_fee) = max_fee_per_gas {
         network::format_eth(gas_limit * max_fee)
-    } else if let Some(price) = final_gas_price {
+    } else if let Some(price) = gas_price {
         network::format_eth(gas_limit * price)
     } else {
         "unknown".to_string()
     };
 
+    let transaction = build_typed_transaction(
+        from,
+        to,
+        data,
+        nonce,
+        chain_id,
+        gas_limit,
+        None,
+        max_fee_per_gas,
+        max_priority_fee_per_gas,
+        gas_price,
+        access_list,
+    );
+
+    let deployment_method = if predicted_contract_address.is_some() {
+        Some(DeploymentMethod::Create2)
+    } else {
+        None
+    };
+    if let Some(addr) = predicted_contract_address {
+        println!("\nPredicted CREATE2 contract address: {}", addr);
+    }
+
     Ok(TxParams {
         mode: TransactionMode::Deploy,
         function_name: None,
         params: None,
-        transaction: TransactionData {
-            tx_type,
-            from,
-            to: None,
-            data,
-            nonce,
-            chain_id,
-            gas_limit,
-            max_fee_per_gas: final_max_fee,
-            max_priority_fee_per_gas: final_priority_fee,
-            gas_price: final_gas_price,
-            value: None,
-        },
+        transaction,
         metadata: Metadata {
             network: NetworkInfo {
                 name: network_name.to_string(),
@@ -340,10 +754,16 @@ async fn prepare_deploy(
             prepared: true,
             signed: false,
             signed_at: None,
+            suggested_fees,
+            predicted_contract_address,
+            deployment_method,
+            verified_rpc_endpoints: None,
+            expected_transfer: None,
         },
     })
 }
 
+#[allow(clippy::too_many_arguments)]
 async fn prepare_deposit(
     client: &RpcClient,
     from: Address,
@@ -355,37 +775,68 @@ async fn prepare_deposit(
     amount: String,
     deadline: u64,
     token: Option<Address>,
+    fee_options: FeeOptions,
+    use_access_list: bool,
+    artifact: Option<&artifact::Artifact>,
 ) -> Result<TxParams> {
     info!("Preparing deposit transaction...");
 
     // Parse amount
     let amount_wei = alloy::primitives::utils::parse_ether(&amount)?;
 
-    // Encode function call
-    let (data, value) =
-        contract::encode_deposit(beneficiary, amount_wei, deadline, token).await?;
+    // Encode function call. A runtime artifact is encoded by selector
+    // lookup against its dynamic ABI; otherwise fall back to the static
+    // `sol!` encoding in `contract`.
+    let token_addr = token.unwrap_or(Address::ZERO);
+    let value = if token.is_none() { Some(amount_wei) } else { None };
+    let data = match artifact {
+        Some(art) => artifact::encode_call(
+            art,
+            "deposit",
+            &[
+                DynSolValue::Address(beneficiary),
+                DynSolValue::Uint(amount_wei, 256),
+                DynSolValue::Uint(U256::from(deadline), 256),
+                DynSolValue::Address(token_addr),
+            ],
+        )?,
+        None => contract::encode_deposit(beneficiary, amount_wei, deadline, token).await?.0,
+    };
 
     // Estimate gas
     let gas_limit = network::estimate_gas(client, from, Some(contract), &data, value).await?;
 
     // Get gas prices
-    let (max_fee_per_gas, max_priority_fee_per_gas, gas_price) =
-        network::get_gas_prices(client).await?;
-
-    // Determine transaction type and set appropriate gas fields
-    let (tx_type, final_max_fee, final_priority_fee, final_gas_price) =
-        if max_fee_per_gas.is_some() {
-            // EIP-1559 transaction (type 2)
-            (2, max_fee_per_gas, max_priority_fee_per_gas, None)
-        } else {
-            // Legacy transaction (type 0)
-            (0, None, None, gas_price)
-        };
+    let (max_fee_per_gas, max_priority_fee_per_gas, gas_price) = network::get_gas_prices(
+        client,
+        fee_options.speed.percentile(),
+        fee_options.multiplier,
+        fee_options.priority_fee_floor,
+    )
+    .await?;
+
+    // Optionally ask the node for an access list to cut execution gas
+    let access_list = if use_access_list {
+        network::create_access_list(client, from, Some(contract), &data, value)
+            .await
+            .map(|(list, _refined_gas)| list)
+            .unwrap_or_default()
+    } else {
+        Vec::new()
+    };
+
+    // Cross-check against eth_feeHistory so a reviewer can sanity-check fees
+    let suggested_fees = network::suggest_fees(client).await.ok().map(
+        |(max_fee_per_gas, max_priority_fee_per_gas)| SuggestedFees {
+            max_fee_per_gas,
+            max_priority_fee_per_gas,
+        },
+    );
 
     // Calculate estimated cost (including value being sent)
-    let gas_cost = if let Some(max_fee) = final_max_fee {
+    let gas_cost = if let Some(max_fee) = max_fee_per_gas {
         gas_limit * max_fee
-    } else if let Some(price) = final_gas_price {
+    } else if let Some(price) = gas_price {
         gas_limit * price
     } else {
         U256::ZERO
@@ -393,6 +844,29 @@ async fn prepare_deposit(
     let total_cost = gas_cost + value.unwrap_or(U256::ZERO);
     let estimated_cost = network::format_eth(total_cost);
 
+    // An ERC-20 deposit moves `amount_wei` tokens from the signer into the
+    // contract, so that's the Transfer log `broadcast --expect-transfer`
+    // should look for; a native-value deposit has no ERC-20 log to check.
+    let expected_transfer = token.map(|token| ExpectedTransfer {
+        token,
+        to: contract,
+        value: amount_wei,
+    });
+
+    let transaction = build_typed_transaction(
+        from,
+        Some(contract),
+        data,
+        nonce,
+        chain_id,
+        gas_limit,
+        value,
+        max_fee_per_gas,
+        max_priority_fee_per_gas,
+        gas_price,
+        access_list,
+    );
+
     Ok(TxParams {
         mode: TransactionMode::Call,
         function_name: Some("deposit".to_string()),
@@ -402,19 +876,7 @@ async fn prepare_deposit(
             "deadline": deadline,
             "token": token,
         })),
-        transaction: TransactionData {
-            tx_type,
-            from,
-            to: Some(contract),
-            data,
-            nonce,
-            chain_id,
-            gas_limit,
-            max_fee_per_gas: final_max_fee,
-            max_priority_fee_per_gas: final_priority_fee,
-            gas_price: final_gas_price,
-            value,
-        },
+        transaction,
         metadata: Metadata {
             network: NetworkInfo {
                 name: network_name.to_string(),
@@ -426,6 +888,11 @@ async fn prepare_deposit(
             prepared: true,
             signed: false,
             signed_at: None,
+            suggested_fees,
+            predicted_contract_address: None,
+            deployment_method: None,
+            verified_rpc_endpoints: None,
+            expected_transfer,
         },
     })
 }
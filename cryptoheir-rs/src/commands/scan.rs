@@ -0,0 +1,140 @@
+//! Scan command - reconstructs CryptoHeir deposit state from on-chain logs (requires network access)
+
+use crate::{
+    contract::{self, CryptoHeirEvent},
+    network,
+    types::{u256_hex, Config},
+    Result,
+};
+use alloy::primitives::{Address, U256};
+use alloy::providers::Provider;
+use serde::Serialize;
+use std::collections::BTreeMap;
+use tracing::info;
+
+/// The reconstructed state of one deposit, folded from its `Deposited` log
+/// and whatever `Claimed`/`Reclaimed`/`DeadlineExtended` logs came after it.
+/// This is the only way to answer "what deposits exist for this
+/// beneficiary" since the contract exposes no view function for it.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DepositState {
+    #[serde(with = "u256_hex")]
+    pub id: U256,
+    pub beneficiary: Address,
+    pub token: Address,
+    #[serde(with = "u256_hex")]
+    pub amount: U256,
+    #[serde(with = "u256_hex")]
+    pub deadline: U256,
+    pub status: DepositStatus,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DepositStatus {
+    Pending,
+    Claimed,
+    Reclaimed,
+}
+
+#[allow(clippy::too_many_arguments)]
+pub async fn execute(
+    contract_addr: Option<Address>,
+    network: Option<String>,
+    rpc_url: Option<String>,
+    from_block: u64,
+    to_block: Option<u64>,
+    beneficiary: Option<Address>,
+    output: String,
+) -> Result<()> {
+    let config = Config::load()?;
+
+    let contract_addr = contract_addr.or(config.contract_address).ok_or_else(|| {
+        eyre::eyre!("No contract address provided (use --contract or set CONTRACT_ADDRESS)")
+    })?;
+
+    let rpc_url = rpc_url
+        .or(config.rpc_url)
+        .or_else(|| {
+            network::get_rpc_url(
+                network.as_deref().unwrap_or("sepolia"),
+                config.infura_api_key.as_deref(),
+            )
+        })
+        .ok_or_else(|| eyre::eyre!("No RPC URL provided or configured"))?;
+
+    info!("Connecting to network via {}", rpc_url);
+    let client = network::create_client(&rpc_url).await?;
+
+    let to_block = match to_block {
+        Some(block) => block,
+        None => client.get_block_number().await?,
+    };
+
+    info!(
+        "Scanning {} logs {}..{} for contract {}",
+        "eth_getLogs", from_block, to_block, contract_addr
+    );
+    let logs = network::get_logs(&client, contract_addr, from_block, to_block).await?;
+    let events = contract::decode_logs(&logs)?;
+
+    // Deposited events arrive before any Claimed/Reclaimed/DeadlineExtended
+    // for the same id (the contract can't emit those before a deposit
+    // exists), so a single ordered pass is enough to fold them into final
+    // per-deposit state.
+    let mut deposits: BTreeMap<U256, DepositState> = BTreeMap::new();
+    for event in events {
+        match event {
+            CryptoHeirEvent::Deposited(e) => {
+                deposits.insert(
+                    e.id,
+                    DepositState {
+                        id: e.id,
+                        beneficiary: e.beneficiary,
+                        token: e.token,
+                        amount: e.amount,
+                        deadline: e.deadline,
+                        status: DepositStatus::Pending,
+                    },
+                );
+            }
+            CryptoHeirEvent::Claimed(e) => {
+                if let Some(deposit) = deposits.get_mut(&e.id) {
+                    deposit.status = DepositStatus::Claimed;
+                }
+            }
+            CryptoHeirEvent::Reclaimed(e) => {
+                if let Some(deposit) = deposits.get_mut(&e.id) {
+                    deposit.status = DepositStatus::Reclaimed;
+                }
+            }
+            CryptoHeirEvent::DeadlineExtended(e) => {
+                if let Some(deposit) = deposits.get_mut(&e.id) {
+                    deposit.deadline = e.newDeadline;
+                }
+            }
+        }
+    }
+
+    // `Deposited` only indexes `id` and `beneficiary`, not the depositor, so
+    // that's the only filter we can apply here.
+    let mut deposits: Vec<DepositState> = deposits.into_values().collect();
+    if let Some(beneficiary) = beneficiary {
+        deposits.retain(|d| d.beneficiary == beneficiary);
+    }
+
+    println!("\nFound {} deposit(s):", deposits.len());
+    for deposit in &deposits {
+        println!(
+            "  #{} beneficiary={} token={} amount={} deadline={} status={:?}",
+            deposit.id, deposit.beneficiary, deposit.token, deposit.amount, deposit.deadline, deposit.status
+        );
+    }
+
+    let json = serde_json::to_string_pretty(&deposits)?;
+    std::fs::write(&output, &json)?;
+    println!("\nDeposit list saved to: {}", output);
+
+    Ok(())
+}
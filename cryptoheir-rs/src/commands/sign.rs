@@ -1,27 +1,37 @@
 //! Sign command - signs prepared transactions offline (no network required)
 
-use crate::{crypto, qr, tui, types::*, Result};
+use crate::{crypto, ledger, qr, tui, types::*, vault, Result};
 use alloy::primitives::Bytes;
 use tracing::{info, warn};
 
+#[allow(clippy::too_many_arguments)]
 pub async fn execute(
     input: String,
     output: String,
     qr_input: Option<String>,
     generate_qr: bool,
     skip_review: bool,
+    use_ledger: bool,
+    hd_path: String,
 ) -> Result<()> {
     info!("Loading transaction parameters...");
 
     // Load tx params from file or QR code
     let tx_params_json = if let Some(qr_file) = qr_input {
         info!("Scanning QR code from {}...", qr_file);
-        qr::scan_qr(&qr_file)?
+        qr::scan_qr_path(&qr_file)?
     } else {
         std::fs::read_to_string(&input)?
     };
 
-    let tx_params: TxParams = serde_json::from_str(&tx_params_json)?;
+    // A batch written by `prepare-batch` is a JSON array of `TxParams`
+    // instead of a single object; sign each item in order and write out a
+    // matching array of `SignedTx`.
+    let parsed: serde_json::Value = serde_json::from_str(&tx_params_json)?;
+    if parsed.is_array() {
+        return sign_batch(parsed, output, generate_qr, skip_review, use_ledger, hd_path).await;
+    }
+    let tx_params: TxParams = serde_json::from_value(parsed)?;
 
     // Verify transaction is prepared but not signed
     if !tx_params.metadata.prepared {
@@ -44,16 +54,18 @@ pub async fn execute(
         display_transaction_summary(&tx_params);
     }
 
-    // Load private key from environment
-    let config = Config::load()?;
-    let private_key = config
-        .private_key
-        .ok_or_else(|| eyre::eyre!("PRIVATE_KEY not set in environment"))?;
-
     info!("Signing transaction...");
 
-    // Sign the transaction
-    let signed_tx = crypto::sign_transaction(&tx_params, &private_key)?;
+    // Sign with a connected Ledger device, keeping the private key off this
+    // machine entirely, or fall back to PRIVATE_KEY / an encrypted mnemonic
+    // vault as before.
+    let signed_tx = if use_ledger {
+        ledger::sign_transaction(&tx_params, &hd_path).await?
+    } else {
+        let config = Config::load()?;
+        let private_key = resolve_private_key(&config)?;
+        crypto::sign_transaction(&tx_params, &private_key).await?
+    };
 
     // Save to file
     let json = serde_json::to_string_pretty(&signed_tx)?;
@@ -65,7 +77,11 @@ pub async fn execute(
     println!("  From: {}", signed_tx.from);
 
     if let Some(addr) = signed_tx.predicted_contract_address {
-        println!("  Contract Address: {}", addr);
+        let method = match signed_tx.deployment_method {
+            Some(DeploymentMethod::Create2) => "CREATE2, deterministic",
+            Some(DeploymentMethod::Create) | None => "CREATE, depends on nonce",
+        };
+        println!("  Contract Address: {} ({})", addr, method);
     }
 
     // Generate QR code if requested
@@ -80,6 +96,94 @@ pub async fn execute(
     Ok(())
 }
 
+/// Resolve a private key from PRIVATE_KEY, or — if that's unset — from an
+/// encrypted mnemonic vault at MNEMONIC_VAULT, prompting for its passphrase
+/// and deriving MNEMONIC_INDEX (default 0) in memory. Neither the decrypted
+/// mnemonic nor the derived key ever touch disk.
+fn resolve_private_key(config: &Config) -> Result<String> {
+    if let Some(private_key) = &config.private_key {
+        return Ok(private_key.clone());
+    }
+
+    let vault_path = config.mnemonic_vault.as_ref().ok_or_else(|| {
+        eyre::eyre!("Neither PRIVATE_KEY nor MNEMONIC_VAULT is set in environment")
+    })?;
+
+    let passphrase = rpassword::prompt_password(format!("Passphrase for {}: ", vault_path))
+        .map_err(|e| eyre::eyre!("Failed to read passphrase: {}", e))?;
+
+    vault::private_key_from_file(vault_path, &passphrase, config.mnemonic_index.unwrap_or(0))
+}
+
+/// Sign every `TxParams` in a batch written by `prepare-batch` in order, writing
+/// the resulting `SignedTx`s out as a JSON array `broadcast` can iterate over
+async fn sign_batch(
+    batch: serde_json::Value,
+    output: String,
+    generate_qr: bool,
+    skip_review: bool,
+    use_ledger: bool,
+    hd_path: String,
+) -> Result<()> {
+    let batch: Vec<TxParams> = serde_json::from_value(batch)?;
+    if batch.is_empty() {
+        return Err(eyre::eyre!("Batch contains no transactions"));
+    }
+
+    let private_key = if use_ledger {
+        None
+    } else {
+        let config = Config::load()?;
+        Some(resolve_private_key(&config)?)
+    };
+
+    let mut signed_batch = Vec::with_capacity(batch.len());
+    for (index, tx_params) in batch.iter().enumerate() {
+        info!("Reviewing batch item {} of {}...", index + 1, batch.len());
+
+        if !tx_params.metadata.prepared {
+            return Err(eyre::eyre!("Batch item {} has not been prepared", index));
+        }
+        if tx_params.metadata.signed {
+            warn!("Batch item {} appears to already be signed", index);
+        }
+
+        if !skip_review {
+            let approved = tui::review_transaction(tx_params)?;
+            if !approved {
+                println!("\n✗ Batch signing cancelled by user at item {}", index);
+                return Ok(());
+            }
+        } else {
+            display_transaction_summary(tx_params);
+        }
+
+        let signed_tx = if use_ledger {
+            ledger::sign_transaction(tx_params, &hd_path).await?
+        } else {
+            crypto::sign_transaction(tx_params, private_key.as_ref().unwrap()).await?
+        };
+        println!("  ✓ Signed item {}: {}", index, signed_tx.tx_hash);
+        signed_batch.push(signed_tx);
+    }
+
+    let json = serde_json::to_string_pretty(&signed_batch)?;
+    std::fs::write(&output, &json)?;
+
+    println!("\n✓ {} transactions signed successfully!", signed_batch.len());
+    println!("  Output: {}", output);
+
+    if generate_qr {
+        info!("Generating QR code...");
+        qr::display_qr(&json)?;
+    }
+
+    println!("\nNext step: Transfer {} to online machine and run:", output);
+    println!("  cryptoheir-rs broadcast -i {}", output);
+
+    Ok(())
+}
+
 fn display_transaction_summary(tx_params: &TxParams) {
     println!("\n{'='}Transaction Review{'='}");
     println!("Network: {} (chain ID: {})",
@@ -90,12 +194,20 @@ fn display_transaction_summary(tx_params: &TxParams) {
     if let Some(fn_name) = &tx_params.function_name {
         println!("Function: {}", fn_name);
     }
-    println!("From: {}", tx_params.transaction.from);
-    if let Some(to) = tx_params.transaction.to {
+    let common = tx_params.transaction.common();
+    println!("From: {}", common.from);
+    if let Some(to) = common.to {
         println!("To: {}", to);
     }
-    println!("Nonce: {}", tx_params.transaction.nonce);
-    println!("Gas Limit: {}", tx_params.transaction.gas_limit);
+    println!("Nonce: {}", common.nonce);
+    println!("Gas Limit: {}", common.gas_limit);
+    if let Some(addr) = tx_params.metadata.predicted_contract_address {
+        let method = match tx_params.metadata.deployment_method {
+            Some(DeploymentMethod::Create2) => "CREATE2, deterministic",
+            Some(DeploymentMethod::Create) | None => "CREATE",
+        };
+        println!("Predicted Contract Address: {} ({})", addr, method);
+    }
     println!("Estimated Cost: {} ETH", tx_params.metadata.estimated_cost);
     println!("{'='}");
 }
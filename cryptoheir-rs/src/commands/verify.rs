@@ -0,0 +1,38 @@
+//! Verify command - independently decodes and checks a signed transaction (works offline)
+
+use crate::{crypto, qr, types::SignedTx, Result};
+use tracing::info;
+
+pub async fn execute(input: String, qr_input: Option<String>) -> Result<()> {
+    info!("Loading signed transaction...");
+
+    // Load signed tx from file or QR code
+    let signed_tx_json = if let Some(qr_file) = qr_input {
+        info!("Scanning QR code from {}...", qr_file);
+        qr::scan_qr_path(&qr_file)?
+    } else {
+        std::fs::read_to_string(&input)?
+    };
+
+    let signed_tx: SignedTx = serde_json::from_str(&signed_tx_json)?;
+
+    info!("Decoding raw envelope and recovering signer...");
+    let verified = crypto::verify_signed_tx(&signed_tx)?;
+
+    println!("\n✓ Signature and encoding verified against the raw envelope!");
+    println!("  From:     {}", verified.from);
+    match verified.to {
+        Some(to) => println!("  To:       {}", to),
+        None => println!("  To:       Contract Creation"),
+    }
+    println!("  Value:    {} wei", verified.value);
+    println!("  Nonce:    {}", verified.nonce);
+    println!("  Chain ID: {}", verified.chain_id);
+    println!("  Gas Limit:{}", verified.gas_limit);
+    println!("  TX Hash:  {}", signed_tx.tx_hash);
+
+    println!("\nThese values were decoded from the raw signed bytes, not the unsigned TxParams.");
+    println!("Compare them against what you intended to sign before broadcasting.");
+
+    Ok(())
+}
@@ -1,10 +1,28 @@
 //! CryptoHeir contract ABI encoding and bytecode loading
 
-use crate::Result;
-use alloy::{
-    primitives::{Address, Bytes, U256},
-    sol,
-};
+use crate::{types::LogEntry, Result};
+use alloy::primitives::{address, keccak256, Address, Bytes, Log, LogData, B256, U256};
+use alloy::sol;
+use alloy::sol_types::SolEvent;
+
+/// The well-known "deterministic deployment proxy" (Arachnid's CREATE2
+/// factory), already deployed at this same address on essentially every EVM
+/// chain. Its calldata convention is `salt (32 bytes) ++ init_code`.
+pub const CREATE2_DEPLOYER: Address = address!("4e59b44847b379578588920cA78FbF26c0B49566");
+
+/// Predict the address a `CREATE2_DEPLOYER` deployment will land at, per
+/// EIP-1014: `keccak256(0xff ++ deployer ++ salt ++ keccak256(init_code))[12..]`
+pub fn predict_create2_address(deployer: Address, salt: B256, init_code: &Bytes) -> Address {
+    let init_code_hash = keccak256(init_code);
+
+    let mut preimage = Vec::with_capacity(1 + 20 + 32 + 32);
+    preimage.push(0xff);
+    preimage.extend_from_slice(deployer.as_slice());
+    preimage.extend_from_slice(salt.as_slice());
+    preimage.extend_from_slice(init_code_hash.as_slice());
+
+    Address::from_slice(&keccak256(preimage)[12..])
+}
 
 // Define the contract ABI using alloy's sol! macro
 sol! {
@@ -16,7 +34,52 @@ sol! {
         function extendDeadline(uint256 id, uint256 newDeadline) external;
         function transferFeeCollector(address newCollector) external;
         function acceptFeeCollector() external;
+
+        event Deposited(uint256 indexed id, address indexed beneficiary, address token, uint256 amount, uint256 deadline);
+        event Claimed(uint256 indexed id);
+        event Reclaimed(uint256 indexed id);
+        event DeadlineExtended(uint256 indexed id, uint256 newDeadline);
+    }
+}
+
+/// A decoded CryptoHeir log, one variant per event the contract can emit.
+/// Reconstructing deposit state (what exists, who claimed it, extended
+/// deadlines) from these is the only option once a deposit has only been
+/// observed on chain, since the `sol!` block above declares no view
+/// functions for it.
+#[derive(Debug, Clone)]
+pub enum CryptoHeirEvent {
+    Deposited(CryptoHeir::Deposited),
+    Claimed(CryptoHeir::Claimed),
+    Reclaimed(CryptoHeir::Reclaimed),
+    DeadlineExtended(CryptoHeir::DeadlineExtended),
+}
+
+/// Decode every CryptoHeir event found in `logs`, silently skipping entries
+/// that don't match any of the four known signatures (e.g. the ERC-20
+/// `Transfer` log emitted by the token being deposited, in the same
+/// receipt as a `Deposited` log).
+pub fn decode_logs(logs: &[LogEntry]) -> Result<Vec<CryptoHeirEvent>> {
+    let mut events = Vec::new();
+
+    for entry in logs {
+        let log = Log {
+            address: entry.address,
+            data: LogData::new_unchecked(entry.topics.clone(), entry.data.clone()),
+        };
+
+        if let Ok(decoded) = CryptoHeir::Deposited::decode_log(&log, true) {
+            events.push(CryptoHeirEvent::Deposited(decoded.data));
+        } else if let Ok(decoded) = CryptoHeir::Claimed::decode_log(&log, true) {
+            events.push(CryptoHeirEvent::Claimed(decoded.data));
+        } else if let Ok(decoded) = CryptoHeir::Reclaimed::decode_log(&log, true) {
+            events.push(CryptoHeirEvent::Reclaimed(decoded.data));
+        } else if let Ok(decoded) = CryptoHeir::DeadlineExtended::decode_log(&log, true) {
+            events.push(CryptoHeirEvent::DeadlineExtended(decoded.data));
+        }
     }
+
+    Ok(events)
 }
 
 /// Load contract bytecode from Foundry artifacts
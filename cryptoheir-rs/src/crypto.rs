@@ -1,49 +1,96 @@
 //! Cryptographic operations for signing transactions
 
 use crate::{
-    types::{SignedTx, TransactionData, TransactionMode, TxParams},
+    types::{
+        AccessListItem, CommonTxFields, DeploymentMethod, SignedTx, TransactionMode, TxParams,
+        TypedTransaction, VerifiedTx,
+    },
     Result,
 };
 use alloy::{
-    consensus::{SignableTransaction, TxEip1559, TxLegacy, TxEnvelope},
-    eips::eip2718::Encodable2718,
-    primitives::{Bytes, TxKind, U256},
-    signers::{local::PrivateKeySigner, Signer},
+    consensus::{SignableTransaction, Transaction, TxEip1559, TxEip2930, TxEnvelope, TxLegacy},
+    eips::{
+        eip2718::{Decodable2718, Encodable2718},
+        eip2930::AccessList,
+    },
+    network::TxSigner,
+    primitives::{Bytes, Signature, TxHash, TxKind, U256},
+    signers::local::PrivateKeySigner,
 };
 
 /// Sign a transaction with a private key
 pub async fn sign_transaction(tx_params: &TxParams, private_key: &str) -> Result<SignedTx> {
     // Parse private key
     let signer: PrivateKeySigner = private_key.parse()?;
+    sign_transaction_with(tx_params, &signer).await
+}
 
+/// Sign a transaction with any [`TxSigner`], local or remote. This is what
+/// lets `commands::sign` use a Ledger device (see [`crate::ledger`])
+/// interchangeably with a local private key: the device only ever sees a
+/// derivation path and returns an address and signatures, never the key
+/// itself. Signing goes through `TxSigner::sign_transaction` rather than a
+/// raw hash so a hardware wallet can show the actual transaction fields for
+/// on-device review instead of blindly signing a hash.
+pub async fn sign_transaction_with<S: TxSigner<Signature> + Sync>(
+    tx_params: &TxParams,
+    signer: &S,
+) -> Result<SignedTx> {
     // Verify the signer address matches the from address
     let signer_address = signer.address();
-    if signer_address != tx_params.transaction.from {
+    let common = tx_params.transaction.common();
+    if signer_address != common.from {
         return Err(eyre::eyre!(
             "Private key address {} does not match transaction from address {}",
             signer_address,
-            tx_params.transaction.from
+            common.from
         ));
     }
 
-    // Create and sign the transaction based on type
-    let (signed_tx_bytes, tx_hash) = match tx_params.transaction.tx_type {
-        2 => sign_eip1559(&tx_params.transaction, &signer).await?,
-        0 => sign_legacy(&tx_params.transaction, &signer).await?,
-        _ => {
-            return Err(eyre::eyre!(
-                "Unsupported transaction type: {}",
-                tx_params.transaction.tx_type
-            ))
+    // Create and sign the transaction based on its variant
+    let (signed_tx_bytes, tx_hash) = match &tx_params.transaction {
+        TypedTransaction::Eip1559 {
+            common,
+            max_fee_per_gas,
+            max_priority_fee_per_gas,
+            access_list,
+        } => {
+            sign_eip1559(
+                common,
+                *max_fee_per_gas,
+                *max_priority_fee_per_gas,
+                access_list,
+                signer,
+            )
+            .await?
+        }
+        TypedTransaction::Eip2930 {
+            common,
+            gas_price,
+            access_list,
+        } => sign_eip2930(common, *gas_price, access_list, signer).await?,
+        TypedTransaction::Legacy { common, gas_price } => {
+            sign_legacy(common, *gas_price, signer).await?
+        }
+        TypedTransaction::Eip4844 { .. } => {
+            return Err(eyre::eyre!("EIP-4844 blob transactions are not yet supported"))
         }
     };
 
-    // Calculate predicted contract address for deployments
-    let predicted_contract_address = if matches!(tx_params.mode, TransactionMode::Deploy) {
-        Some(signer_address.create(tx_params.transaction.nonce))
-    } else {
-        None
-    };
+    // Calculate predicted contract address for deployments. A CREATE2
+    // deployment already computed (and surfaced) its address during
+    // `prepare`; plain CREATE only becomes knowable once the nonce is fixed.
+    let (predicted_contract_address, deployment_method) =
+        if let Some(addr) = tx_params.metadata.predicted_contract_address {
+            (Some(addr), Some(DeploymentMethod::Create2))
+        } else if matches!(tx_params.mode, TransactionMode::Deploy) {
+            (
+                Some(signer_address.create(common.nonce)),
+                Some(DeploymentMethod::Create),
+            )
+        } else {
+            (None, None)
+        };
 
     // Create updated metadata
     let mut metadata = tx_params.metadata.clone();
@@ -54,46 +101,44 @@ pub async fn sign_transaction(tx_params: &TxParams, private_key: &str) -> Result
         signed_transaction: signed_tx_bytes,
         tx_hash,
         mode: tx_params.mode.clone(),
-        from: tx_params.transaction.from,
+        from: common.from,
         predicted_contract_address,
+        deployment_method,
         metadata,
     })
 }
 
 /// Sign an EIP-1559 (Type 2) transaction
-async fn sign_eip1559(
-    tx_data: &TransactionData,
-    signer: &PrivateKeySigner,
-) -> Result<(Bytes, alloy::primitives::TxHash)> {
-    let max_fee_per_gas = tx_data
-        .max_fee_per_gas
-        .ok_or_else(|| eyre::eyre!("max_fee_per_gas required for EIP-1559"))?;
-    let max_priority_fee_per_gas = tx_data
-        .max_priority_fee_per_gas
-        .ok_or_else(|| eyre::eyre!("max_priority_fee_per_gas required for EIP-1559"))?;
-
-    let tx = TxEip1559 {
-        chain_id: tx_data.chain_id,
-        nonce: tx_data.nonce,
-        gas_limit: tx_data.gas_limit.to::<u64>(),
+async fn sign_eip1559<S: TxSigner<Signature> + Sync>(
+    common: &CommonTxFields,
+    max_fee_per_gas: U256,
+    max_priority_fee_per_gas: U256,
+    access_list: &[AccessListItem],
+    signer: &S,
+) -> Result<(Bytes, TxHash)> {
+    let mut tx = TxEip1559 {
+        chain_id: common.chain_id,
+        nonce: common.nonce,
+        gas_limit: common.gas_limit.to::<u64>(),
         max_fee_per_gas: max_fee_per_gas.to::<u128>(),
         max_priority_fee_per_gas: max_priority_fee_per_gas.to::<u128>(),
-        to: tx_data.to.map(TxKind::Call).unwrap_or(TxKind::Create),
-        value: tx_data.value.unwrap_or(U256::ZERO),
-        input: tx_data.data.clone(),
-        access_list: Default::default(),
+        to: common.to.map(TxKind::Call).unwrap_or(TxKind::Create),
+        value: common.value.unwrap_or(U256::ZERO),
+        input: common.data.clone(),
+        access_list: to_alloy_access_list(access_list),
     };
 
-    // Get the signature hash
-    let tx_hash = tx.signature_hash();
+    // Sign through the transaction-signing flow (not a raw hash), so a
+    // hardware wallet can parse and display the actual fields for on-device
+    // review rather than blindly signing a hash it can't verify
+    let signature = signer.sign_transaction(&mut tx).await?;
 
-    // Sign the hash with the private key
-    let sig_hash = tx.signature_hash();
-    let signature = signer.sign_hash(&sig_hash).await?;
-
-    // Create signed transaction and wrap in TxEnvelope
+    // Create signed transaction and wrap in TxEnvelope. The transaction hash
+    // that identifies it on chain is the hash of the *signed* envelope, not
+    // the signing hash the signature was made over.
     let signed_tx = tx.into_signed(signature);
     let envelope = TxEnvelope::Eip1559(signed_tx);
+    let tx_hash = *envelope.tx_hash();
 
     // Encode using alloy's encoding
     let encoded = envelope.encoded_2718();
@@ -101,38 +146,124 @@ async fn sign_eip1559(
     Ok((encoded.into(), tx_hash))
 }
 
-/// Sign a legacy (Type 0) transaction
-async fn sign_legacy(
-    tx_data: &TransactionData,
-    signer: &PrivateKeySigner,
-) -> Result<(Bytes, alloy::primitives::TxHash)> {
-    let gas_price = tx_data
-        .gas_price
-        .ok_or_else(|| eyre::eyre!("gas_price required for legacy transaction"))?;
-
-    let tx = TxLegacy {
-        chain_id: Some(tx_data.chain_id),
-        nonce: tx_data.nonce,
+/// Sign an EIP-2930 (Type 1) access-list transaction
+async fn sign_eip2930<S: TxSigner<Signature> + Sync>(
+    common: &CommonTxFields,
+    gas_price: U256,
+    access_list: &[AccessListItem],
+    signer: &S,
+) -> Result<(Bytes, TxHash)> {
+    let mut tx = TxEip2930 {
+        chain_id: common.chain_id,
+        nonce: common.nonce,
         gas_price: gas_price.to::<u128>(),
-        gas_limit: tx_data.gas_limit.to::<u64>(),
-        to: tx_data.to.map(TxKind::Call).unwrap_or(TxKind::Create),
-        value: tx_data.value.unwrap_or(U256::ZERO),
-        input: tx_data.data.clone(),
+        gas_limit: common.gas_limit.to::<u64>(),
+        to: common.to.map(TxKind::Call).unwrap_or(TxKind::Create),
+        value: common.value.unwrap_or(U256::ZERO),
+        input: common.data.clone(),
+        access_list: to_alloy_access_list(access_list),
     };
 
-    // Get the signature hash
-    let tx_hash = tx.signature_hash();
+    // Sign through the transaction-signing flow (not a raw hash), so a
+    // hardware wallet can parse and display the actual fields for on-device
+    // review rather than blindly signing a hash it can't verify
+    let signature = signer.sign_transaction(&mut tx).await?;
+
+    // Create signed transaction and wrap in TxEnvelope. The transaction hash
+    // that identifies it on chain is the hash of the *signed* envelope, not
+    // the signing hash the signature was made over.
+    let signed_tx = tx.into_signed(signature);
+    let envelope = TxEnvelope::Eip2930(signed_tx);
+    let tx_hash = *envelope.tx_hash();
+
+    // Encode using alloy's encoding
+    let encoded = envelope.encoded_2718();
+
+    Ok((encoded.into(), tx_hash))
+}
+
+/// Sign a legacy (Type 0) transaction
+async fn sign_legacy<S: TxSigner<Signature> + Sync>(
+    common: &CommonTxFields,
+    gas_price: U256,
+    signer: &S,
+) -> Result<(Bytes, TxHash)> {
+    let mut tx = TxLegacy {
+        chain_id: Some(common.chain_id),
+        nonce: common.nonce,
+        gas_price: gas_price.to::<u128>(),
+        gas_limit: common.gas_limit.to::<u64>(),
+        to: common.to.map(TxKind::Call).unwrap_or(TxKind::Create),
+        value: common.value.unwrap_or(U256::ZERO),
+        input: common.data.clone(),
+    };
 
-    // Sign the hash with the private key
-    let sig_hash = tx.signature_hash();
-    let signature = signer.sign_hash(&sig_hash).await?;
+    // Sign through the transaction-signing flow (not a raw hash), so a
+    // hardware wallet can parse and display the actual fields for on-device
+    // review rather than blindly signing a hash it can't verify
+    let signature = signer.sign_transaction(&mut tx).await?;
 
-    // Create signed transaction and wrap in TxEnvelope
+    // Create signed transaction and wrap in TxEnvelope. The transaction hash
+    // that identifies it on chain is the hash of the *signed* envelope, not
+    // the signing hash the signature was made over.
     let signed_tx = tx.into_signed(signature);
     let envelope = TxEnvelope::Legacy(signed_tx);
+    let tx_hash = *envelope.tx_hash();
 
     // Encode using alloy's encoding
     let encoded = envelope.encoded_2718();
 
     Ok((encoded.into(), tx_hash))
 }
+
+/// Independently decode a raw EIP-2718 envelope and confirm it actually
+/// encodes what `SignedTx` claims: the signature recovers to `from`, and the
+/// decoded transaction hashes to `tx_hash`. Lets a second air-gapped machine
+/// verify a `SignedTx` blob before it's ever trusted for broadcast.
+pub fn verify_signed_tx(signed_tx: &SignedTx) -> Result<VerifiedTx> {
+    let mut bytes = signed_tx.signed_transaction.as_ref();
+    let envelope = TxEnvelope::decode_2718(&mut bytes)
+        .map_err(|e| eyre::eyre!("Failed to decode signed transaction: {}", e))?;
+
+    let recovered_from = envelope
+        .recover_signer()
+        .map_err(|e| eyre::eyre!("Failed to recover signer from signature: {}", e))?;
+    if recovered_from != signed_tx.from {
+        return Err(eyre::eyre!(
+            "Recovered signer {} does not match claimed from address {}",
+            recovered_from,
+            signed_tx.from
+        ));
+    }
+
+    let decoded_hash = *envelope.tx_hash();
+    if decoded_hash != signed_tx.tx_hash {
+        return Err(eyre::eyre!(
+            "Decoded transaction hash {} does not match claimed tx_hash {}",
+            decoded_hash,
+            signed_tx.tx_hash
+        ));
+    }
+
+    Ok(VerifiedTx {
+        from: recovered_from,
+        to: envelope.to(),
+        value: envelope.value(),
+        nonce: envelope.nonce(),
+        chain_id: envelope.chain_id().unwrap_or_default(),
+        gas_limit: envelope.gas_limit(),
+    })
+}
+
+/// Convert our wire-format access list entries into alloy's `AccessList`
+fn to_alloy_access_list(items: &[AccessListItem]) -> AccessList {
+    AccessList(
+        items
+            .iter()
+            .map(|item| alloy::eips::eip2930::AccessListItem {
+                address: item.address,
+                storage_keys: item.storage_keys.clone(),
+            })
+            .collect(),
+    )
+}
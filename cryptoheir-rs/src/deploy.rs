@@ -0,0 +1,40 @@
+//! Deterministic contract deployment through a CREATE2 deployer proxy
+//!
+//! Wraps [`contract::load_bytecode`] and [`contract::predict_create2_address`]
+//! into a single idempotent entry point: the same salt and constructor
+//! arguments always predict the same address on every chain, and deploying
+//! a second time to an address that already has code is an error rather
+//! than a silent (and wasted-gas) no-op.
+
+use crate::{contract, network, Result};
+use alloy::primitives::{Address, Bytes, B256};
+use alloy::providers::Provider;
+
+/// Build `init_code` for a deployment: the contract's creation bytecode
+/// followed by ABI-encoded constructor arguments, if any.
+pub fn init_code(constructor_args: &[u8]) -> Result<Bytes> {
+    let mut code = contract::load_bytecode()?.to_vec();
+    code.extend_from_slice(constructor_args);
+    Ok(Bytes::from(code))
+}
+
+/// Predict the address a CREATE2 deployment through `deployer` with `salt`
+/// and `constructor_args` will land at, without needing network access.
+pub fn predicted_address(deployer: Address, salt: B256, constructor_args: &[u8]) -> Result<Address> {
+    let code = init_code(constructor_args)?;
+    Ok(contract::predict_create2_address(deployer, salt, &code))
+}
+
+/// Refuse to proceed if `address` already has deployed code, so re-running
+/// a deployment against the same salt is safe: it fails clearly instead of
+/// silently re-sending the same bytecode.
+pub async fn assert_not_deployed(client: &impl Provider, address: Address) -> Result<()> {
+    let existing_code = network::get_code(client, address).await?;
+    if !existing_code.is_empty() {
+        return Err(eyre::eyre!(
+            "Contract already deployed at predicted CREATE2 address {}",
+            address
+        ));
+    }
+    Ok(())
+}
@@ -0,0 +1,42 @@
+//! Ledger hardware-wallet signing backend
+//!
+//! The private key never leaves the device: `LedgerSigner` only ever hands
+//! back a derived address and, later, a signature. `crypto::sign_transaction_with`
+//! signs through `TxSigner::sign_transaction` rather than a raw hash, so the
+//! device parses and displays the actual transaction fields for on-device
+//! review instead of blindly signing a hash it has no way to verify. This
+//! lets `commands::sign` produce the exact same `SignedTx` shape as
+//! local-key signing (see [`crate::crypto::sign_transaction_with`]), so
+//! `broadcast` doesn't need to know or care which backend produced it.
+
+use crate::{
+    types::{SignedTx, TxParams},
+    Result,
+};
+use alloy_signer_ledger::{HDPath, LedgerSigner};
+
+/// Default BIP-44 path for the first Ethereum account (`m/44'/60'/0'/0/0`),
+/// matching what most wallets default to.
+pub const DEFAULT_HD_PATH: &str = "m/44'/60'/0'/0/0";
+
+/// Connect to a Ledger device over HID and return a signer for `hd_path`
+/// (e.g. `m/44'/60'/0'/0/3`). Requires the device to be unlocked with the
+/// Ethereum app open.
+pub async fn connect(hd_path: &str) -> Result<LedgerSigner> {
+    LedgerSigner::new(HDPath::Other(hd_path.to_string()), None)
+        .await
+        .map_err(|e| {
+            eyre::eyre!(
+                "Failed to connect to Ledger device at path {}: {}. \
+                 Make sure it's unlocked with the Ethereum app open.",
+                hd_path,
+                e
+            )
+        })
+}
+
+/// Sign a prepared transaction with a connected Ledger device.
+pub async fn sign_transaction(tx_params: &TxParams, hd_path: &str) -> Result<SignedTx> {
+    let signer = connect(hd_path).await?;
+    crate::crypto::sign_transaction_with(tx_params, &signer).await
+}
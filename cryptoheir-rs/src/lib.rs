@@ -3,13 +3,17 @@
 //! This library provides air-gapped offline transaction signing for the CryptoHeir
 //! smart contract, with support for interactive TUI and QR code-based data transfer.
 
+pub mod artifact;
 pub mod commands;
 pub mod contract;
 pub mod crypto;
+pub mod deploy;
+pub mod ledger;
 pub mod network;
 pub mod qr;
 pub mod tui;
 pub mod types;
+pub mod vault;
 
 // Re-export commonly used types
 pub use types::{Config, SignedTx, TxParams};
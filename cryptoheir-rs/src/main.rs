@@ -43,6 +43,102 @@ enum Commands {
         /// Generate QR code for offline transfer
         #[arg(long)]
         qr: bool,
+
+        /// Fee tier used to pick the eth_feeHistory reward percentile
+        #[arg(long, value_enum, default_value = "standard")]
+        fee_speed: commands::prepare::FeeSpeed,
+
+        /// Multiplier applied to the latest base fee before adding the
+        /// priority fee, to tolerate base-fee increases before broadcast
+        #[arg(long, env = "FEE_MULTIPLIER", default_value_t = 2.0)]
+        fee_multiplier: f64,
+
+        /// Floor, in gwei, under which the suggested priority fee is never
+        /// clamped down
+        #[arg(long, env = "PRIORITY_FEE_FLOOR_GWEI", default_value_t = 1)]
+        priority_fee_floor_gwei: u64,
+
+        /// Request an access list from eth_createAccessList and attach it to
+        /// the transaction to reduce execution gas
+        #[arg(long)]
+        access_list: bool,
+
+        /// Independent RPC endpoint to cross-check chain ID, nonce, and
+        /// contract bytecode against before trusting the primary one
+        /// (repeatable)
+        #[arg(long)]
+        verify_rpc: Vec<String>,
+
+        /// Allow preparing a transaction whose signer address has deployed
+        /// code (EIP-3607 would reject it at broadcast time otherwise)
+        #[arg(long)]
+        allow_contract_sender: bool,
+
+        /// Override the fetched nonce, e.g. to prepare a replacement
+        /// transaction for one that's stuck in the mempool at the same nonce
+        #[arg(long)]
+        nonce: Option<u64>,
+
+        /// Directory of versioned `{abi, bytecode}` artifacts (e.g.
+        /// `CryptoHeir.v2.json`), used instead of the binary's compiled-in
+        /// ABI/bytecode when paired with --contract-version
+        #[arg(long, env = "ARTIFACT_DIR")]
+        artifact_dir: Option<String>,
+
+        /// Artifact version to load from --artifact-dir (e.g. "v2"); falls
+        /// back to the static compiled-in ABI/bytecode when unset
+        #[arg(long, env = "CONTRACT_VERSION")]
+        contract_version: Option<String>,
+    },
+
+    /// Prepare a batch of operations in one offline signing pass (requires network access)
+    ///
+    /// Reads a JSON manifest of operations, fetches the starting nonce once,
+    /// and assigns each item the next nonce in sequence. Outputs a JSON
+    /// array of unsigned transactions that `sign` can iterate over.
+    PrepareBatch {
+        /// Path to a JSON manifest: an array of operations, e.g.
+        /// `[{"Deposit": {"beneficiary": "0x..", "amount": "1.0", "deadline": 1999999999, "token": null, "contract": null}}]`
+        manifest: String,
+
+        /// Network to use (mainnet, sepolia, polygon-mainnet, etc.)
+        #[arg(short, long, env = "NETWORK")]
+        network: Option<String>,
+
+        /// Custom RPC URL (overrides network selection)
+        #[arg(long, env = "RPC_URL")]
+        rpc_url: Option<String>,
+
+        /// Output file path
+        #[arg(short, long, default_value = "tx-batch.json")]
+        output: String,
+
+        /// Generate QR code for offline transfer
+        #[arg(long)]
+        qr: bool,
+
+        /// Fee tier used to pick the eth_feeHistory reward percentile
+        #[arg(long, value_enum, default_value = "standard")]
+        fee_speed: commands::prepare::FeeSpeed,
+
+        /// Multiplier applied to the latest base fee before adding the
+        /// priority fee, to tolerate base-fee increases before broadcast
+        #[arg(long, env = "FEE_MULTIPLIER", default_value_t = 2.0)]
+        fee_multiplier: f64,
+
+        /// Floor, in gwei, under which the suggested priority fee is never
+        /// clamped down
+        #[arg(long, env = "PRIORITY_FEE_FLOOR_GWEI", default_value_t = 1)]
+        priority_fee_floor_gwei: u64,
+
+        /// Request an access list from eth_createAccessList for every item
+        #[arg(long)]
+        access_list: bool,
+
+        /// Allow preparing transactions whose signer address has deployed
+        /// code (EIP-3607 would reject it at broadcast time otherwise)
+        #[arg(long)]
+        allow_contract_sender: bool,
     },
 
     /// Sign a prepared transaction (works offline, no network required)
@@ -60,7 +156,9 @@ enum Commands {
         #[arg(short, long, default_value = "signed-tx.json")]
         output: String,
 
-        /// Scan QR code from image file instead of reading JSON
+        /// Scan QR code(s) instead of reading JSON: a single image file, a
+        /// directory of frame images, or a `<dir>/<prefix>*` pattern, for
+        /// payloads too large for one code
         #[arg(long)]
         qr_input: Option<String>,
 
@@ -71,6 +169,15 @@ enum Commands {
         /// Skip interactive TUI review (use with caution)
         #[arg(long)]
         skip_review: bool,
+
+        /// Sign with a connected Ledger device instead of PRIVATE_KEY, so the
+        /// private key never touches this machine
+        #[arg(long)]
+        ledger: bool,
+
+        /// BIP-44 derivation path to use with --ledger
+        #[arg(long, default_value = cryptoheir_rs::ledger::DEFAULT_HD_PATH)]
+        hd_path: String,
     },
 
     /// Broadcast a signed transaction (requires network access)
@@ -94,10 +201,90 @@ enum Commands {
         #[arg(short, long, default_value = "signed-tx-receipt.json")]
         output: String,
 
-        /// Scan QR code from image file instead of reading JSON
+        /// Scan QR code(s) instead of reading JSON: a single image file, a
+        /// directory of frame images, or a `<dir>/<prefix>*` pattern, for
+        /// payloads too large for one code
+        #[arg(long)]
+        qr_input: Option<String>,
+
+        /// Fail the command unless the receipt contains the ERC-20 Transfer
+        /// event recorded in the prepared transaction's metadata (e.g. for a
+        /// token deposit), even if the transaction itself didn't revert
+        #[arg(long)]
+        expect_transfer: bool,
+
+        /// Fail the command unless the receipt contains a log with this
+        /// topic0 (hex, e.g. 0x1234...), for events other than Transfer
+        #[arg(long)]
+        expect_event: Option<String>,
+
+        /// Number of confirmations to wait for before returning success,
+        /// counting the block the transaction was mined in as the first, to
+        /// tolerate a shallow reorg discarding the receipt
+        #[arg(long, default_value_t = 1)]
+        confirmations: u64,
+    },
+
+    /// Verify a signed transaction independently of how it was produced (works offline)
+    ///
+    /// This command decodes the raw signed envelope, recovers the signer
+    /// address from the signature, and checks it against the claimed
+    /// `from` address and transaction hash. Use it on a second air-gapped
+    /// machine to confirm a `signed-tx.json`/QR blob really encodes the
+    /// transaction it claims before ever broadcasting it.
+    Verify {
+        /// Input file path (signed-tx.json)
+        #[arg(short, long, default_value = "signed-tx.json")]
+        input: String,
+
+        /// Scan QR code(s) instead of reading JSON: a single image file, a
+        /// directory of frame images, or a `<dir>/<prefix>*` pattern, for
+        /// payloads too large for one code
         #[arg(long)]
         qr_input: Option<String>,
     },
+
+    /// Generate, derive from, and encrypt BIP-39 mnemonic phrases (works offline)
+    Mnemonic {
+        #[command(subcommand)]
+        action: commands::mnemonic::MnemonicAction,
+    },
+
+    /// Reconstruct CryptoHeir deposit state from on-chain logs (requires network access)
+    ///
+    /// Range-queries `eth_getLogs` for the contract's `Deposited`, `Claimed`,
+    /// `Reclaimed`, and `DeadlineExtended` events and folds them into a list
+    /// of deposits with their current status, since the contract exposes no
+    /// view function for this.
+    Scan {
+        /// CryptoHeir contract address to scan (falls back to CONTRACT_ADDRESS)
+        #[arg(short, long)]
+        contract: Option<alloy::primitives::Address>,
+
+        /// Network to use (mainnet, sepolia, polygon-mainnet, etc.)
+        #[arg(short, long, env = "NETWORK")]
+        network: Option<String>,
+
+        /// Custom RPC URL (overrides network selection)
+        #[arg(long, env = "RPC_URL")]
+        rpc_url: Option<String>,
+
+        /// First block to scan (inclusive)
+        #[arg(long, default_value_t = 0)]
+        from_block: u64,
+
+        /// Last block to scan (inclusive); defaults to the chain's latest block
+        #[arg(long)]
+        to_block: Option<u64>,
+
+        /// Only include deposits for this beneficiary
+        #[arg(long)]
+        beneficiary: Option<alloy::primitives::Address>,
+
+        /// Output file path
+        #[arg(short, long, default_value = "deposits.json")]
+        output: String,
+    },
 }
 
 #[tokio::main]
@@ -128,8 +315,59 @@ async fn main() -> Result<()> {
             rpc_url,
             output,
             qr,
+            fee_speed,
+            fee_multiplier,
+            priority_fee_floor_gwei,
+            access_list,
+            verify_rpc,
+            allow_contract_sender,
+            nonce,
+            artifact_dir,
+            contract_version,
+        } => {
+            commands::prepare::execute(
+                operation,
+                network,
+                rpc_url,
+                output,
+                qr,
+                fee_speed,
+                fee_multiplier,
+                priority_fee_floor_gwei,
+                access_list,
+                verify_rpc,
+                allow_contract_sender,
+                nonce,
+                artifact_dir,
+                contract_version,
+            )
+            .await?;
+        }
+        Commands::PrepareBatch {
+            manifest,
+            network,
+            rpc_url,
+            output,
+            qr,
+            fee_speed,
+            fee_multiplier,
+            priority_fee_floor_gwei,
+            access_list,
+            allow_contract_sender,
         } => {
-            commands::prepare::execute(operation, network, rpc_url, output, qr).await?;
+            commands::prepare::execute_batch(
+                manifest,
+                network,
+                rpc_url,
+                output,
+                qr,
+                fee_speed,
+                fee_multiplier,
+                priority_fee_floor_gwei,
+                access_list,
+                allow_contract_sender,
+            )
+            .await?;
         }
         Commands::Sign {
             input,
@@ -137,8 +375,10 @@ async fn main() -> Result<()> {
             qr_input,
             qr,
             skip_review,
+            ledger,
+            hd_path,
         } => {
-            commands::sign::execute(input, output, qr_input, qr, skip_review).await?;
+            commands::sign::execute(input, output, qr_input, qr, skip_review, ledger, hd_path).await?;
         }
         Commands::Broadcast {
             input,
@@ -146,8 +386,38 @@ async fn main() -> Result<()> {
             rpc_url,
             output,
             qr_input,
+            expect_transfer,
+            expect_event,
+            confirmations,
+        } => {
+            commands::broadcast::execute(
+                input,
+                network,
+                rpc_url,
+                output,
+                qr_input,
+                expect_transfer,
+                expect_event,
+                confirmations,
+            )
+            .await?;
+        }
+        Commands::Verify { input, qr_input } => {
+            commands::verify::execute(input, qr_input).await?;
+        }
+        Commands::Mnemonic { action } => {
+            commands::mnemonic::execute(action).await?;
+        }
+        Commands::Scan {
+            contract,
+            network,
+            rpc_url,
+            from_block,
+            to_block,
+            beneficiary,
+            output,
         } => {
-            commands::broadcast::execute(input, network, rpc_url, output, qr_input).await?;
+            commands::scan::execute(contract, network, rpc_url, from_block, to_block, beneficiary, output).await?;
         }
     }
 
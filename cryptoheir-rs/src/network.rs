@@ -1,11 +1,14 @@
 //! Network utilities for RPC communication
 
-use crate::{types::TxReceipt, Result};
+use crate::{
+    types::{AccessListItem, ExpectedTransfer, LogEntry, TxReceipt},
+    Result,
+};
 use alloy::{
     network::{Ethereum, EthereumWallet},
-    primitives::{Address, Bytes, TxHash, U256},
+    primitives::{keccak256, Address, Bytes, TxHash, B256, U256},
     providers::{Provider, ProviderBuilder, RootProvider},
-    rpc::types::TransactionReceipt,
+    rpc::types::{Filter, TransactionReceipt},
     transports::http::{Client, Http},
 };
 use std::collections::HashMap;
@@ -57,31 +60,71 @@ pub async fn get_chain_id(client: &impl Provider) -> Result<u64> {
     Ok(client.get_chain_id().await?)
 }
 
-/// Get nonce for an address
+/// Get the next nonce for an address from the pending block, so a nonce
+/// already consumed by a transaction sitting in the mempool (but not yet
+/// mined) is accounted for instead of being handed out again
 pub async fn get_nonce(client: &impl Provider, address: Address) -> Result<u64> {
-    Ok(client.get_transaction_count(address).await?)
+    Ok(client.get_transaction_count(address).pending().await?)
+}
+
+/// Get the deployed bytecode at an address (empty for an EOA or an address
+/// with nothing deployed)
+pub async fn get_code(client: &impl Provider, address: Address) -> Result<Bytes> {
+    Ok(client.get_code_at(address).await?)
 }
 
 /// Get gas prices (returns EIP-1559 or legacy)
+///
+/// `priority_percentile` selects the `eth_feeHistory` reward column to read
+/// (e.g. 25 for a "slow" tip, 50 for "standard", 75 for "fast"); the
+/// priority fee is the median of that column across the last 10 blocks,
+/// clamped to `priority_fee_floor`, falling back to
+/// `eth_maxPriorityFeePerGas` if every block's column is empty. `max_fee_per_gas`
+/// is set to `base_fee * fee_multiplier + priority_fee` to tolerate a few
+/// base-fee increases before broadcast.
 pub async fn get_gas_prices(
     client: &impl Provider,
+    priority_percentile: f64,
+    fee_multiplier: f64,
+    priority_fee_floor: U256,
 ) -> Result<(Option<U256>, Option<U256>, Option<U256>)> {
     // Try to get EIP-1559 fee estimates first
-    match client.get_fee_history(10, Default::default(), &[]).await {
+    match client
+        .get_fee_history(10, Default::default(), &[priority_percentile])
+        .await
+    {
         Ok(fee_history) => {
             // Get latest base fee
             let base_fee = fee_history
                 .latest_block_base_fee()
                 .unwrap_or(U256::from(1_000_000_000u64)); // 1 gwei default
 
-            // Set priority fee (tip)
-            let max_priority_fee_per_gas = U256::from(1_500_000_000u64); // 1.5 gwei
+            let mut priority_fees: Vec<U256> = fee_history
+                .reward
+                .unwrap_or_default()
+                .into_iter()
+                .filter_map(|percentiles| percentiles.first().copied())
+                .map(U256::from)
+                .filter(|fee| *fee > U256::ZERO)
+                .collect();
 
-            // Max fee = 2x base fee + priority fee (to handle fluctuations)
-            let max_fee_per_gas = base_fee * U256::from(2) + max_priority_fee_per_gas;
+            let max_priority_fee_per_gas = if priority_fees.is_empty() {
+                client
+                    .get_max_priority_fee_per_gas()
+                    .await
+                    .unwrap_or(U256::from(1_000_000_000u64))
+            } else {
+                priority_fees.sort();
+                priority_fees[priority_fees.len() / 2]
+            }
+            .max(priority_fee_floor);
+
+            let max_fee_per_gas = scale_u256(base_fee, fee_multiplier) + max_priority_fee_per_gas;
 
             info!(
-                "Using EIP-1559: max_fee={} gwei, priority_fee={} gwei",
+                "Using EIP-1559 ({}th percentile, {}x multiplier): max_fee={} gwei, priority_fee={} gwei",
+                priority_percentile,
+                fee_multiplier,
                 max_fee_per_gas / U256::from(1_000_000_000u64),
                 max_priority_fee_per_gas / U256::from(1_000_000_000u64)
             );
@@ -101,6 +144,150 @@ pub async fn get_gas_prices(
     }
 }
 
+/// Multiply a `U256` wei amount by a floating-point factor, going through
+/// fixed-point millis so the result stays an integer
+fn scale_u256(value: U256, factor: f64) -> U256 {
+    let factor_millis = (factor * 1000.0).round().max(0.0) as u64;
+    value * U256::from(factor_millis) / U256::from(1000u64)
+}
+
+/// Suggest `(max_fee_per_gas, max_priority_fee_per_gas)` from recent
+/// `eth_feeHistory` data, for sanity-checking or pre-filling fees during
+/// `prepare`.
+///
+/// Requests the last 20 blocks with reward percentiles `[10, 50, 90]` and
+/// takes the median of the 50th-percentile column as the priority fee
+/// (falling back to a 1 gwei floor if every block reports zero), then sets
+/// `max_fee_per_gas = pending_base_fee * 2 + priority_fee` to tolerate a
+/// couple of base-fee increases before broadcast. Falls back to
+/// `eth_gasPrice` if the node doesn't support `eth_feeHistory` or returns an
+/// empty `baseFeePerGas`.
+pub async fn suggest_fees(client: &impl Provider) -> Result<(U256, U256)> {
+    const BLOCK_COUNT: u64 = 20;
+    const PRIORITY_FEE_FLOOR: u64 = 1_000_000_000; // 1 gwei
+    const REWARD_PERCENTILES: &[f64] = &[10.0, 50.0, 90.0];
+    const MEDIAN_COLUMN: usize = 1; // index of the 50th percentile above
+
+    let fee_history = match client
+        .get_fee_history(BLOCK_COUNT, Default::default(), REWARD_PERCENTILES)
+        .await
+    {
+        Ok(history) if history.base_fee_per_gas.is_empty() => {
+            return fallback_gas_price(client, PRIORITY_FEE_FLOOR).await;
+        }
+        Ok(history) => history,
+        Err(_) => return fallback_gas_price(client, PRIORITY_FEE_FLOOR).await,
+    };
+
+    let pending_base_fee = fee_history
+        .next_block_base_fee()
+        .unwrap_or(U256::from(PRIORITY_FEE_FLOOR));
+
+    let mut priority_fees: Vec<U256> = fee_history
+        .reward
+        .unwrap_or_default()
+        .into_iter()
+        .filter_map(|percentiles| percentiles.get(MEDIAN_COLUMN).copied())
+        .map(U256::from)
+        .filter(|fee| *fee > U256::ZERO)
+        .collect();
+
+    let max_priority_fee_per_gas = if priority_fees.is_empty() {
+        U256::from(PRIORITY_FEE_FLOOR)
+    } else {
+        priority_fees.sort();
+        priority_fees[priority_fees.len() / 2]
+    };
+
+    let max_fee_per_gas = pending_base_fee * U256::from(2) + max_priority_fee_per_gas;
+
+    info!(
+        "Suggested fees: max_fee={} gwei, priority_fee={} gwei",
+        max_fee_per_gas / U256::from(1_000_000_000u64),
+        max_priority_fee_per_gas / U256::from(1_000_000_000u64)
+    );
+
+    Ok((max_fee_per_gas, max_priority_fee_per_gas))
+}
+
+async fn fallback_gas_price(client: &impl Provider, priority_fee_floor: u64) -> Result<(U256, U256)> {
+    let gas_price = client.get_gas_price().await?;
+    Ok((gas_price, U256::from(priority_fee_floor)))
+}
+
+/// Cross-check chain ID, account nonce, and (if `contract` is given) the
+/// deployed bytecode hash against one or more independent RPC endpoints,
+/// refusing to continue unless every one of them agrees with the values
+/// already observed on `primary_client`. Returns the full list of endpoints
+/// consulted (primary first) for recording in `Metadata`.
+pub async fn cross_check_rpc(
+    primary_client: &impl Provider,
+    primary_url: &str,
+    other_urls: &[String],
+    expected_chain_id: u64,
+    expected_nonce: u64,
+    signer_address: Address,
+    contract: Option<Address>,
+) -> Result<Vec<String>> {
+    let expected_code_hash = match contract {
+        Some(addr) => Some(alloy::primitives::keccak256(
+            primary_client.get_code_at(addr).await?,
+        )),
+        None => None,
+    };
+
+    let mut consulted = vec![primary_url.to_string()];
+
+    for url in other_urls {
+        let client = create_client(url).await?;
+
+        let chain_id = get_chain_id(&client).await?;
+        if chain_id != expected_chain_id {
+            return Err(eyre::eyre!(
+                "Cross-RPC mismatch: {} reports chain ID {} but {} reports {}",
+                url,
+                chain_id,
+                primary_url,
+                expected_chain_id
+            ));
+        }
+
+        let nonce = get_nonce(&client, signer_address).await?;
+        if nonce != expected_nonce {
+            return Err(eyre::eyre!(
+                "Cross-RPC mismatch: {} reports nonce {} for {} but {} reports {}",
+                url,
+                nonce,
+                signer_address,
+                primary_url,
+                expected_nonce
+            ));
+        }
+
+        if let (Some(addr), Some(expected_hash)) = (contract, expected_code_hash) {
+            let code_hash = alloy::primitives::keccak256(client.get_code_at(addr).await?);
+            if code_hash != expected_hash {
+                return Err(eyre::eyre!(
+                    "Cross-RPC mismatch: {} reports a different bytecode hash for {} than {}",
+                    url,
+                    addr,
+                    primary_url
+                ));
+            }
+        }
+
+        info!(
+            "Cross-checked {} against {}: chain ID, nonce{} agree",
+            url,
+            primary_url,
+            if contract.is_some() { ", and bytecode hash" } else { "" }
+        );
+        consulted.push(url.clone());
+    }
+
+    Ok(consulted)
+}
+
 /// Estimate gas for a transaction
 pub async fn estimate_gas(
     client: &impl Provider,
@@ -131,6 +318,75 @@ pub async fn estimate_gas(
     Ok(gas_with_buffer)
 }
 
+/// Call `eth_createAccessList` for a drafted call and return the
+/// `(address, storageKeys[])` entries the node reports plus its refined gas
+/// estimate, so `prepare` can attach an access list to cut execution gas
+pub async fn create_access_list(
+    client: &impl Provider,
+    from: Address,
+    to: Option<Address>,
+    data: &Bytes,
+    value: Option<U256>,
+) -> Result<(Vec<AccessListItem>, U256)> {
+    let mut tx = alloy::rpc::types::TransactionRequest::default()
+        .from(from)
+        .input(data.clone().into());
+
+    if let Some(to_addr) = to {
+        tx = tx.to(to_addr);
+    }
+
+    if let Some(val) = value {
+        tx = tx.value(val);
+    }
+
+    let result = client.create_access_list(&tx).await?;
+
+    let access_list: Vec<AccessListItem> = result
+        .access_list
+        .0
+        .into_iter()
+        .map(|item| AccessListItem {
+            address: item.address,
+            storage_keys: item.storage_keys,
+        })
+        .collect();
+
+    info!(
+        "eth_createAccessList: {} entries, gas_used={}",
+        access_list.len(),
+        result.gas_used
+    );
+
+    Ok((access_list, result.gas_used))
+}
+
+/// Query `eth_getLogs` for every log `address` emitted between `from_block`
+/// and `to_block` (inclusive), for `scan` to reconstruct CryptoHeir deposit
+/// state from on-chain history without a subgraph or database.
+pub async fn get_logs(
+    client: &impl Provider,
+    address: Address,
+    from_block: u64,
+    to_block: u64,
+) -> Result<Vec<LogEntry>> {
+    let filter = Filter::new()
+        .address(address)
+        .from_block(from_block)
+        .to_block(to_block);
+
+    let logs = client.get_logs(&filter).await?;
+
+    Ok(logs
+        .into_iter()
+        .map(|log| LogEntry {
+            address: log.address(),
+            topics: log.topics().to_vec(),
+            data: log.data().data.clone(),
+        })
+        .collect())
+}
+
 /// Format Wei to ETH string
 pub fn format_eth(wei: U256) -> String {
     let eth = wei.to_string().parse::<f64>().unwrap_or(0.0) / 1e18;
@@ -157,18 +413,24 @@ pub async fn broadcast_transaction(
     Ok(*pending_tx.tx_hash())
 }
 
-/// Wait for transaction receipt
-pub async fn wait_for_receipt(client: &impl Provider, tx_hash: TxHash) -> Result<TxReceipt> {
+/// Wait for a transaction's first receipt, then (if `confirmations > 1`)
+/// keep polling the chain head until `confirmations - 1` blocks have been
+/// built on top of it (the block it was mined in counts as the first
+/// confirmation), so the caller isn't acting on a receipt a reorg could
+/// still erase. `confirmations <= 1` returns as soon as the receipt is mined.
+pub async fn wait_for_receipt(
+    client: &impl Provider,
+    tx_hash: TxHash,
+    confirmations: u64,
+) -> Result<TxReceipt> {
     // Poll for receipt with timeout
     let mut attempts = 0;
     const MAX_ATTEMPTS: u32 = 60; // 5 minutes with 5s intervals
     const POLL_INTERVAL: std::time::Duration = std::time::Duration::from_secs(5);
 
-    loop {
+    let receipt = loop {
         match client.get_transaction_receipt(tx_hash).await? {
-            Some(receipt) => {
-                return receipt_to_tx_receipt(receipt);
-            }
+            Some(receipt) => break receipt,
             None => {
                 attempts += 1;
                 if attempts >= MAX_ATTEMPTS {
@@ -177,20 +439,193 @@ pub async fn wait_for_receipt(client: &impl Provider, tx_hash: TxHash) -> Result
                 tokio::time::sleep(POLL_INTERVAL).await;
             }
         }
+    };
+
+    let mined_block = receipt
+        .block_number
+        .ok_or_else(|| eyre::eyre!("No block number"))?;
+
+    if confirmations > 1 {
+        let target_block = mined_block + confirmations - 1;
+        let mut attempts = 0;
+        loop {
+            let current_block = client.get_block_number().await?;
+            if current_block >= target_block {
+                break;
+            }
+            attempts += 1;
+            if attempts >= MAX_ATTEMPTS {
+                return Err(eyre::eyre!(
+                    "Timeout waiting for {} confirmation(s) on block {}",
+                    confirmations,
+                    mined_block
+                ));
+            }
+            info!(
+                "Waiting for confirmations: {}/{} blocks",
+                current_block.saturating_sub(mined_block) + 1,
+                confirmations
+            );
+            tokio::time::sleep(POLL_INTERVAL).await;
+        }
     }
+
+    receipt_to_tx_receipt(client, receipt).await
 }
 
-/// Convert alloy receipt to our TxReceipt type
-fn receipt_to_tx_receipt(receipt: TransactionReceipt) -> Result<TxReceipt> {
+/// Convert alloy receipt to our TxReceipt type, replaying the transaction
+/// with `eth_call` to decode a revert reason when it failed
+async fn receipt_to_tx_receipt(
+    client: &impl Provider,
+    receipt: TransactionReceipt,
+) -> Result<TxReceipt> {
+    let status = receipt.status();
+    let block_number = receipt.block_number.ok_or_else(|| eyre::eyre!("No block number"))?;
+
+    let revert_reason = if !status {
+        replay_revert_reason(client, receipt.transaction_hash, block_number).await
+    } else {
+        None
+    };
+
+    if !status {
+        match &revert_reason {
+            Some(reason) => info!("Transaction reverted: {}", reason),
+            None => info!("Transaction reverted (reason could not be decoded)"),
+        }
+    }
+
+    let logs = receipt
+        .inner
+        .logs()
+        .iter()
+        .map(|log| LogEntry {
+            address: log.address(),
+            topics: log.topics().to_vec(),
+            data: log.data().data.clone(),
+        })
+        .collect();
+
     Ok(TxReceipt {
         transaction_hash: receipt.transaction_hash,
-        block_number: receipt.block_number.ok_or_else(|| eyre::eyre!("No block number"))?,
+        block_number,
         block_hash: format!("{:?}", receipt.block_hash.ok_or_else(|| eyre::eyre!("No block hash"))?),
         from: receipt.from,
         to: receipt.to,
+        tx_type: receipt.transaction_type(),
         gas_used: receipt.gas_used.to_string(),
-        status: if receipt.status() { 1 } else { 0 },
+        cumulative_gas_used: receipt.inner.cumulative_gas_used().to_string(),
+        effective_gas_price: receipt.effective_gas_price.to_string(),
+        logs_bloom: format!("{:?}", receipt.inner.logs_bloom()),
+        status: if status { 1 } else { 0 },
+        revert_reason,
         contract_address: receipt.contract_address,
+        logs,
         metadata: HashMap::new(),
     })
 }
+
+/// `keccak256("Transfer(address,address,uint256)")`, the topic0 of every
+/// ERC-20 `Transfer` event
+pub fn transfer_event_topic0() -> B256 {
+    keccak256("Transfer(address,address,uint256)")
+}
+
+/// Assert the receipt contains an ERC-20 `Transfer` log matching `expected`.
+/// Fails even when `receipt.status == 1`, so a transaction that merely
+/// didn't revert but silently skipped the transfer (e.g. a zero-value path
+/// in the token contract) is still caught before broadcast is reported as
+/// fully successful.
+pub fn assert_transfer_event(receipt: &TxReceipt, expected: &ExpectedTransfer) -> Result<()> {
+    let topic0 = transfer_event_topic0();
+    let expected_to_topic = B256::left_padding_from(expected.to.as_slice());
+
+    let found = receipt.logs.iter().any(|log| {
+        log.address == expected.token
+            && log.topics.len() == 3
+            && log.topics[0] == topic0
+            && log.topics[2] == expected_to_topic
+            && U256::from_be_slice(&log.data) == expected.value
+    });
+
+    if found {
+        Ok(())
+    } else {
+        Err(eyre::eyre!(
+            "Expected Transfer({} -> {}, {} wei) event not found in receipt logs",
+            expected.token,
+            expected.to,
+            expected.value
+        ))
+    }
+}
+
+/// Assert at least one log in the receipt carries `topic0`, for a generic
+/// `--expect-event` check when the caller only knows the event signature
+/// rather than a full decoded expectation
+pub fn assert_event_topic(receipt: &TxReceipt, topic0: B256) -> Result<()> {
+    if receipt.logs.iter().any(|log| log.topics.first() == Some(&topic0)) {
+        Ok(())
+    } else {
+        Err(eyre::eyre!(
+            "Expected event with topic0 {} not found in receipt logs",
+            topic0
+        ))
+    }
+}
+
+/// Re-run a reverted transaction with `eth_call` at the block it was mined
+/// in and try to decode a standard `Error(string)` or `Panic(uint256)`
+/// revert reason from the RPC error's returned data.
+async fn replay_revert_reason(
+    client: &impl Provider,
+    tx_hash: TxHash,
+    block_number: u64,
+) -> Option<String> {
+    let tx = client.get_transaction_by_hash(tx_hash).await.ok().flatten()?;
+
+    let mut request = alloy::rpc::types::TransactionRequest::default()
+        .from(tx.from)
+        .input(tx.input.clone().into())
+        .value(tx.value);
+    if let Some(to) = tx.to {
+        request = request.to(to);
+    }
+
+    match client
+        .call(&request)
+        .block(alloy::eips::BlockId::number(block_number))
+        .await
+    {
+        Ok(_) => None,
+        Err(err) => extract_revert_data(&err).and_then(|data| decode_revert_reason(&data)),
+    }
+}
+
+/// Pull the raw revert bytes out of an `eth_call` RPC error response, if any
+fn extract_revert_data(err: &alloy::transports::RpcError<alloy::transports::TransportErrorKind>) -> Option<Bytes> {
+    let error_payload = err.as_error_resp()?;
+    let raw = error_payload.data.as_ref()?;
+    raw.get().trim_matches('"').parse::<Bytes>().ok()
+}
+
+/// Decode a revert reason from returned call data: the standard
+/// `Error(string)` selector (`0x08c379a0`) or `Panic(uint256)` (`0x4e487b71`)
+pub fn decode_revert_reason(data: &Bytes) -> Option<String> {
+    if data.len() < 4 {
+        return None;
+    }
+    let (selector, payload) = data.split_at(4);
+    match selector {
+        [0x08, 0xc3, 0x79, 0xa0] => {
+            <String as alloy::sol_types::SolValue>::abi_decode(payload, true)
+                .ok()
+                .map(|reason| format!("Error({})", reason))
+        }
+        [0x4e, 0x48, 0x7b, 0x71] => {
+            let code = U256::try_from_be_slice(payload.get(..32)?)?;
+            Some(format!("Panic(0x{:02x})", code))
+        }
+        _ => None,
+    }
+}
@@ -3,61 +3,354 @@
 use crate::Result;
 use image::Luma;
 use qrcode::QrCode;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 
-/// Display a QR code in the terminal
-pub fn display_qr(data: &str) -> Result<()> {
-    let code = QrCode::new(data.as_bytes())?;
+/// Maximum raw payload bytes per QR frame before base64 encoding. Chosen to
+/// keep the rendered code well within a phone camera's scannable range even
+/// at a conservative error-correction level.
+const MAX_CHUNK_BYTES: usize = 500;
+
+/// Delay between frames when animating a multi-part QR in the terminal
+const FRAME_DELAY: std::time::Duration = std::time::Duration::from_millis(700);
+
+/// How many times to cycle through all frames in an unattended terminal
+/// animation before giving up
+const ANIMATION_LOOPS: usize = 20;
+
+/// One frame of a (possibly single-frame) chunked QR payload. Serialized as
+/// the literal content of the QR code itself.
+#[derive(Debug, Serialize, Deserialize)]
+struct QrFrame {
+    seq: u16,
+    total: u16,
+    crc32: u32,
+    data: String,
+}
+
+/// Split `data` into one or more base64-encoded, CRC-checked frames, each
+/// ready to be rendered as its own QR code
+fn encode_frames(data: &str) -> Vec<QrFrame> {
+    let bytes = data.as_bytes();
+    let crc = crc32(bytes);
+    let chunks: Vec<&[u8]> = if bytes.is_empty() {
+        vec![&bytes[..]]
+    } else {
+        bytes.chunks(MAX_CHUNK_BYTES).collect()
+    };
+    let total = chunks.len() as u16;
+
+    chunks
+        .into_iter()
+        .enumerate()
+        .map(|(i, chunk)| QrFrame {
+            seq: i as u16,
+            total,
+            crc32: crc,
+            data: base64_encode(chunk),
+        })
+        .collect()
+}
+
+/// Reassemble frames (in any order, duplicates tolerated) into the original
+/// string, verifying every frame agrees on `total`/`crc32`, that all `seq`
+/// indices from `0..total` are present, and that the reassembled bytes
+/// still match the checksum
+fn reassemble_frames(frames: &[QrFrame]) -> Result<String> {
+    let first = frames
+        .first()
+        .ok_or_else(|| eyre::eyre!("No frames to reassemble"))?;
+    let total = first.total;
+    let crc = first.crc32;
+
+    let mut by_seq: BTreeMap<u16, &QrFrame> = BTreeMap::new();
+    for frame in frames {
+        if frame.total != total || frame.crc32 != crc {
+            return Err(eyre::eyre!(
+                "Frame {} belongs to a different payload (mismatched total/crc32)",
+                frame.seq
+            ));
+        }
+        by_seq.insert(frame.seq, frame);
+    }
+
+    if by_seq.len() != total as usize {
+        let have: Vec<u16> = by_seq.keys().copied().collect();
+        return Err(eyre::eyre!(
+            "Missing frames: have {:?}, need 0..{}",
+            have,
+            total
+        ));
+    }
+
+    let mut payload = Vec::new();
+    for seq in 0..total {
+        let frame = by_seq
+            .get(&seq)
+            .ok_or_else(|| eyre::eyre!("Missing frame {}", seq))?;
+        payload.extend(base64_decode(&frame.data)?);
+    }
+
+    if crc32(&payload) != crc {
+        return Err(eyre::eyre!("Reassembled payload failed checksum verification"));
+    }
+
+    String::from_utf8(payload).map_err(|e| eyre::eyre!("Reassembled payload is not valid UTF-8: {}", e))
+}
 
-    // Render as ASCII art for terminal display
-    let string = code
+/// Render a `QrFrame` as ASCII art
+fn render_frame(frame: &QrFrame) -> Result<String> {
+    let content = serde_json::to_string(frame)?;
+    let code = QrCode::new(content.as_bytes())?;
+    Ok(code
         .render::<char>()
         .quiet_zone(false)
         .module_dimensions(2, 1)
-        .build();
+        .build())
+}
+
+/// Display a QR code in the terminal, animating through frames if the
+/// payload didn't fit in a single code
+pub fn display_qr(data: &str) -> Result<()> {
+    let frames = encode_frames(data);
+
+    if frames.len() == 1 {
+        println!("\n{}", render_frame(&frames[0])?);
+        println!("\nQR Code generated. Scan with your mobile device or save to file.");
+        return Ok(());
+    }
 
-    println!("\n{}", string);
-    println!("\nQR Code generated. Scan with your mobile device or save to file.");
+    println!(
+        "\nPayload split into {} QR frames; cycling through them (Ctrl+C to stop once scanned)...",
+        frames.len()
+    );
+    for loop_index in 0..ANIMATION_LOOPS {
+        for frame in &frames {
+            print!("\x1B[2J\x1B[1;1H"); // clear screen, move cursor home
+            println!("{}", render_frame(frame)?);
+            println!(
+                "\nFrame {}/{} (loop {}/{})",
+                frame.seq + 1,
+                frame.total,
+                loop_index + 1,
+                ANIMATION_LOOPS
+            );
+            std::thread::sleep(FRAME_DELAY);
+        }
+    }
 
     Ok(())
 }
 
-/// Save QR code to an image file
+/// Save QR code(s) to an image file. A single-frame payload is saved as
+/// `filename`; a multi-frame payload is saved as `name-1.png`, `name-2.png`,
+/// ... `name-N.png` alongside it.
 pub fn save_qr_to_file(data: &str, filename: &str) -> Result<()> {
-    let code = QrCode::new(data.as_bytes())?;
-
-    // Render to image
-    let image = code.render::<Luma<u8>>().build();
+    let frames = encode_frames(data);
 
-    // Save to file
-    image.save(filename)?;
+    if frames.len() == 1 {
+        let content = serde_json::to_string(&frames[0])?;
+        let code = QrCode::new(content.as_bytes())?;
+        let image = code.render::<Luma<u8>>().build();
+        image.save(filename)?;
+        println!("QR code saved to: {}", filename);
+        return Ok(());
+    }
 
-    println!("QR code saved to: {}", filename);
+    for frame in &frames {
+        let frame_filename = framed_filename(filename, frame.seq + 1);
+        let content = serde_json::to_string(frame)?;
+        let code = QrCode::new(content.as_bytes())?;
+        let image = code.render::<Luma<u8>>().build();
+        image.save(&frame_filename)?;
+        println!("QR frame {}/{} saved to: {}", frame.seq + 1, frame.total, frame_filename);
+    }
 
     Ok(())
 }
 
-/// Scan QR code from an image file
-pub fn scan_qr(filename: &str) -> Result<String> {
-    // Load the image
+/// Insert a 1-based frame index before a filename's extension, e.g.
+/// `signed-tx.png` + frame 2 -> `signed-tx-2.png`
+fn framed_filename(filename: &str, frame_number: u16) -> String {
+    match filename.rsplit_once('.') {
+        Some((stem, ext)) => format!("{}-{}.{}", stem, frame_number, ext),
+        None => format!("{}-{}", filename, frame_number),
+    }
+}
+
+/// Decode the raw QR content (our `QrFrame` JSON) out of a single image file
+fn decode_frame_from_image(filename: &str) -> Result<QrFrame> {
     let img = image::open(filename)
         .map_err(|e| eyre::eyre!("Failed to open image {}: {}", filename, e))?;
-
-    // Convert to luma (grayscale)
     let img_luma = img.to_luma8();
-
-    // Prepare image for rqrr
     let mut img_rqrr = rqrr::PreparedImage::prepare(img_luma);
 
-    // Find and decode QR codes
     let grids = img_rqrr.detect_grids();
     if grids.is_empty() {
         return Err(eyre::eyre!("No QR code found in image"));
     }
 
-    // Decode the first QR code found
     let (_, content) = grids[0].decode()?;
+    serde_json::from_str(&content)
+        .map_err(|e| eyre::eyre!("QR content in {} is not a recognized frame: {}", filename, e))
+}
+
+/// Scan a single QR code image file and return its decoded content. Errors
+/// if the image holds one frame of a multi-part payload — use
+/// [`scan_qr_multi`] to scan every frame together.
+pub fn scan_qr(filename: &str) -> Result<String> {
+    let frame = decode_frame_from_image(filename)?;
+    if frame.total > 1 {
+        return Err(eyre::eyre!(
+            "{} is frame {}/{} of a multi-part QR payload; scan all frames with scan_qr_multi",
+            filename,
+            frame.seq + 1,
+            frame.total
+        ));
+    }
+    reassemble_frames(&[frame])
+}
+
+/// Scan several QR code image files (in any order, duplicates tolerated)
+/// and reassemble them into the original payload, verifying the checksum
+pub fn scan_qr_multi(filenames: &[String]) -> Result<String> {
+    if filenames.is_empty() {
+        return Err(eyre::eyre!("No QR frame images provided"));
+    }
+    let frames: Vec<QrFrame> = filenames
+        .iter()
+        .map(|filename| decode_frame_from_image(filename))
+        .collect::<Result<_>>()?;
+    reassemble_frames(&frames)
+}
+
+/// Scan a QR payload from whatever `path` points at: a single image, a
+/// directory of frame images (as written by [`save_qr_to_file`]), or a
+/// `<dir>/<prefix>*` pattern matching frame filenames by prefix. This is the
+/// entry point `sign --qr-input`/`broadcast --qr-input` use, so oversized
+/// payloads that spilled across multiple QR codes are handled transparently.
+pub fn scan_qr_path(path: &str) -> Result<String> {
+    if let Some(prefix_pattern) = path.strip_suffix('*') {
+        let pattern_path = std::path::Path::new(prefix_pattern);
+        let dir = match pattern_path.parent() {
+            Some(parent) if !parent.as_os_str().is_empty() => parent,
+            _ => std::path::Path::new("."),
+        };
+        let prefix = pattern_path
+            .file_name()
+            .and_then(|f| f.to_str())
+            .unwrap_or("")
+            .to_string();
+
+        let mut matches: Vec<String> = std::fs::read_dir(dir)
+            .map_err(|e| eyre::eyre!("Failed to read directory {}: {}", dir.display(), e))?
+            .filter_map(|entry| entry.ok())
+            .map(|entry| entry.path())
+            .filter(|p| {
+                p.file_name()
+                    .and_then(|f| f.to_str())
+                    .map(|f| f.starts_with(&prefix))
+                    .unwrap_or(false)
+            })
+            .map(|p| p.to_string_lossy().into_owned())
+            .collect();
+        matches.sort();
+        return scan_qr_multi(&matches);
+    }
+
+    let meta = std::fs::metadata(path)
+        .map_err(|e| eyre::eyre!("Failed to access {}: {}", path, e))?;
+    if meta.is_dir() {
+        let mut entries: Vec<String> = std::fs::read_dir(path)
+            .map_err(|e| eyre::eyre!("Failed to read directory {}: {}", path, e))?
+            .filter_map(|entry| entry.ok())
+            .map(|entry| entry.path())
+            .filter(|p| p.extension().and_then(|e| e.to_str()) == Some("png"))
+            .map(|p| p.to_string_lossy().into_owned())
+            .collect();
+        entries.sort();
+        return scan_qr_multi(&entries);
+    }
+
+    scan_qr(path)
+}
+
+/// Standard (IEEE 802.3) CRC-32, implemented locally so frame checksums
+/// don't pull in a new dependency for a handful of bytes
+fn crc32(data: &[u8]) -> u32 {
+    const POLY: u32 = 0xEDB88320;
+    let mut crc = 0xFFFFFFFFu32;
+    for &byte in data {
+        crc ^= byte as u32;
+        for _ in 0..8 {
+            crc = if crc & 1 != 0 {
+                (crc >> 1) ^ POLY
+            } else {
+                crc >> 1
+            };
+        }
+    }
+    !crc
+}
+
+const BASE64_ALPHABET: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+/// Standard base64 encoding, implemented locally to keep QR frame payloads
+/// self-contained without a new dependency
+fn base64_encode(data: &[u8]) -> String {
+    let mut out = String::with_capacity(data.len().div_ceil(3) * 4);
+    for chunk in data.chunks(3) {
+        let b0 = chunk[0];
+        let b1 = *chunk.get(1).unwrap_or(&0);
+        let b2 = *chunk.get(2).unwrap_or(&0);
+
+        out.push(BASE64_ALPHABET[(b0 >> 2) as usize] as char);
+        out.push(BASE64_ALPHABET[(((b0 & 0x03) << 4) | (b1 >> 4)) as usize] as char);
+        out.push(if chunk.len() > 1 {
+            BASE64_ALPHABET[(((b1 & 0x0f) << 2) | (b2 >> 6)) as usize] as char
+        } else {
+            '='
+        });
+        out.push(if chunk.len() > 2 {
+            BASE64_ALPHABET[(b2 & 0x3f) as usize] as char
+        } else {
+            '='
+        });
+    }
+    out
+}
+
+/// Standard base64 decoding, the inverse of [`base64_encode`]
+fn base64_decode(encoded: &str) -> Result<Vec<u8>> {
+    fn value(byte: u8) -> Result<u8> {
+        BASE64_ALPHABET
+            .iter()
+            .position(|&b| b == byte)
+            .map(|pos| pos as u8)
+            .ok_or_else(|| eyre::eyre!("Invalid base64 byte: {}", byte as char))
+    }
+
+    let stripped = encoded.trim_end_matches('=');
+    let mut out = Vec::with_capacity(stripped.len() * 3 / 4);
+    let bytes = stripped.as_bytes();
+
+    for chunk in bytes.chunks(4) {
+        let v0 = value(chunk[0])?;
+        let v1 = value(*chunk.get(1).unwrap_or(&b'A'))?;
+        out.push((v0 << 2) | (v1 >> 4));
+
+        if let Some(&c2) = chunk.get(2) {
+            let v2 = value(c2)?;
+            out.push((v1 << 4) | (v2 >> 2));
+            if let Some(&c3) = chunk.get(3) {
+                let v3 = value(c3)?;
+                out.push((v2 << 6) | v3);
+            }
+        }
+    }
 
-    Ok(content)
+    Ok(out)
 }
 
 #[cfg(test)]
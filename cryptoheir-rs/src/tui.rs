@@ -1,6 +1,9 @@
 //! Terminal UI for interactive transaction review
 
-use crate::{types::TxParams, Result};
+use crate::{
+    types::{DeploymentMethod, TxParams, TypedTransaction},
+    Result,
+};
 use crossterm::{
     event::{self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode},
     execute,
@@ -119,6 +122,7 @@ fn ui(f: &mut Frame, tx_params: &TxParams, scroll: u16) {
 
 fn create_transaction_details(tx_params: &TxParams) -> Text<'static> {
     let mut lines = Vec::new();
+    let common = tx_params.transaction.common();
 
     // Network info
     lines.push(Line::from(vec![
@@ -155,10 +159,10 @@ fn create_transaction_details(tx_params: &TxParams) -> Text<'static> {
     // From/To
     lines.push(Line::from(vec![
         Span::styled("From: ", Style::default().add_modifier(Modifier::BOLD)),
-        Span::raw(format!("{}", tx_params.transaction.from)),
+        Span::raw(format!("{}", common.from)),
     ]));
 
-    if let Some(to) = tx_params.transaction.to {
+    if let Some(to) = common.to {
         lines.push(Line::from(vec![
             Span::styled("To: ", Style::default().add_modifier(Modifier::BOLD)),
             Span::raw(format!("{}", to)),
@@ -173,7 +177,7 @@ fn create_transaction_details(tx_params: &TxParams) -> Text<'static> {
     lines.push(Line::from(""));
 
     // Value
-    if let Some(value) = tx_params.transaction.value {
+    if let Some(value) = common.value {
         if value > alloy::primitives::U256::ZERO {
             lines.push(Line::from(vec![
                 Span::styled("Value: ", Style::default().add_modifier(Modifier::BOLD)),
@@ -188,39 +192,76 @@ fn create_transaction_details(tx_params: &TxParams) -> Text<'static> {
     // Gas details
     lines.push(Line::from(vec![
         Span::styled("Nonce: ", Style::default().add_modifier(Modifier::BOLD)),
-        Span::raw(format!("{}", tx_params.transaction.nonce)),
+        Span::raw(format!("{}", common.nonce)),
     ]));
 
     lines.push(Line::from(vec![
         Span::styled("Gas Limit: ", Style::default().add_modifier(Modifier::BOLD)),
-        Span::raw(format!("{}", tx_params.transaction.gas_limit)),
+        Span::raw(format!("{}", common.gas_limit)),
     ]));
 
-    if let Some(max_fee) = tx_params.transaction.max_fee_per_gas {
-        lines.push(Line::from(vec![
-            Span::styled(
-                "Max Fee Per Gas: ",
-                Style::default().add_modifier(Modifier::BOLD),
-            ),
-            Span::raw(format!("{} gwei", max_fee / alloy::primitives::U256::from(1_000_000_000u64))),
-        ]));
-    }
-
-    if let Some(priority_fee) = tx_params.transaction.max_priority_fee_per_gas {
-        lines.push(Line::from(vec![
-            Span::styled(
-                "Priority Fee: ",
-                Style::default().add_modifier(Modifier::BOLD),
-            ),
-            Span::raw(format!("{} gwei", priority_fee / alloy::primitives::U256::from(1_000_000_000u64))),
-        ]));
-    }
-
-    if let Some(gas_price) = tx_params.transaction.gas_price {
-        lines.push(Line::from(vec![
-            Span::styled("Gas Price: ", Style::default().add_modifier(Modifier::BOLD)),
-            Span::raw(format!("{} gwei", gas_price / alloy::primitives::U256::from(1_000_000_000u64))),
-        ]));
+    match &tx_params.transaction {
+        TypedTransaction::Eip1559 {
+            max_fee_per_gas,
+            max_priority_fee_per_gas,
+            ..
+        } => {
+            lines.push(Line::from(vec![
+                Span::styled(
+                    "Max Fee Per Gas: ",
+                    Style::default().add_modifier(Modifier::BOLD),
+                ),
+                Span::raw(format!(
+                    "{} gwei",
+                    *max_fee_per_gas / alloy::primitives::U256::from(1_000_000_000u64)
+                )),
+            ]));
+            lines.push(Line::from(vec![
+                Span::styled(
+                    "Priority Fee: ",
+                    Style::default().add_modifier(Modifier::BOLD),
+                ),
+                Span::raw(format!(
+                    "{} gwei",
+                    *max_priority_fee_per_gas / alloy::primitives::U256::from(1_000_000_000u64)
+                )),
+            ]));
+        }
+        TypedTransaction::Legacy { gas_price, .. } | TypedTransaction::Eip2930 { gas_price, .. } => {
+            lines.push(Line::from(vec![
+                Span::styled("Gas Price: ", Style::default().add_modifier(Modifier::BOLD)),
+                Span::raw(format!(
+                    "{} gwei",
+                    *gas_price / alloy::primitives::U256::from(1_000_000_000u64)
+                )),
+            ]));
+        }
+        TypedTransaction::Eip4844 {
+            max_fee_per_gas,
+            max_priority_fee_per_gas,
+            ..
+        } => {
+            lines.push(Line::from(vec![
+                Span::styled(
+                    "Max Fee Per Gas: ",
+                    Style::default().add_modifier(Modifier::BOLD),
+                ),
+                Span::raw(format!(
+                    "{} gwei",
+                    *max_fee_per_gas / alloy::primitives::U256::from(1_000_000_000u64)
+                )),
+            ]));
+            lines.push(Line::from(vec![
+                Span::styled(
+                    "Priority Fee: ",
+                    Style::default().add_modifier(Modifier::BOLD),
+                ),
+                Span::raw(format!(
+                    "{} gwei",
+                    *max_priority_fee_per_gas / alloy::primitives::U256::from(1_000_000_000u64)
+                )),
+            ]));
+        }
     }
 
     lines.push(Line::from(""));
@@ -241,6 +282,54 @@ fn create_transaction_details(tx_params: &TxParams) -> Text<'static> {
         ),
     ]));
 
+    if let Some(addr) = tx_params.metadata.predicted_contract_address {
+        let method = match tx_params.metadata.deployment_method {
+            Some(DeploymentMethod::Create2) => "CREATE2, deterministic",
+            Some(DeploymentMethod::Create) | None => "CREATE",
+        };
+        lines.push(Line::from(vec![
+            Span::styled(
+                "Predicted Contract Address: ",
+                Style::default().add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(format!("{} ({})", addr, method), Style::default().fg(Color::Cyan)),
+        ]));
+        lines.push(Line::from(""));
+    }
+
+    if let Some(suggested) = &tx_params.metadata.suggested_fees {
+        lines.push(Line::from(vec![
+            Span::styled(
+                "Suggested (eth_feeHistory): ",
+                Style::default().add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(
+                format!(
+                    "max {} gwei / priority {} gwei",
+                    suggested.max_fee_per_gas / alloy::primitives::U256::from(1_000_000_000u64),
+                    suggested.max_priority_fee_per_gas
+                        / alloy::primitives::U256::from(1_000_000_000u64)
+                ),
+                Style::default().fg(Color::Cyan),
+            ),
+        ]));
+        lines.push(Line::from(""));
+    }
+
+    let access_list = tx_params.transaction.access_list().unwrap_or_default();
+    if !access_list.is_empty() {
+        lines.push(Line::from(vec![Span::styled(
+            "Access List: ",
+            Style::default().add_modifier(Modifier::BOLD),
+        )]));
+        for item in access_list {
+            lines.push(Line::from(Span::raw(format!("  {}", item.address))));
+            for key in &item.storage_keys {
+                lines.push(Line::from(Span::raw(format!("    slot {}", key))));
+            }
+        }
+    }
+
     lines.push(Line::from(""));
 
     // Parameters (if any)
@@ -1,6 +1,6 @@
 //! Core types for transaction preparation, signing, and broadcasting
 
-use alloy::primitives::{Address, Bytes, TxHash, U256};
+use alloy::primitives::{Address, Bytes, TxHash, B256, U256};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
@@ -12,6 +12,11 @@ pub struct Config {
     pub infura_api_key: Option<String>,
     pub rpc_url: Option<String>,
     pub contract_address: Option<Address>,
+    /// Path to an encrypted mnemonic vault (see [`crate::vault`]), used by
+    /// `sign` in place of `PRIVATE_KEY` when set
+    pub mnemonic_vault: Option<String>,
+    /// Account index to derive from the vault's mnemonic (default 0)
+    pub mnemonic_index: Option<u32>,
 }
 
 impl Config {
@@ -30,6 +35,10 @@ impl Config {
             contract_address: std::env::var("CONTRACT_ADDRESS")
                 .ok()
                 .and_then(|s| s.parse().ok()),
+            mnemonic_vault: std::env::var("MNEMONIC_VAULT").ok(),
+            mnemonic_index: std::env::var("MNEMONIC_INDEX")
+                .ok()
+                .and_then(|s| s.parse().ok()),
         })
     }
 }
@@ -52,7 +61,7 @@ pub struct TxParams {
     pub function_name: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub params: Option<serde_json::Value>,
-    pub transaction: TransactionData,
+    pub transaction: TypedTransaction,
     pub metadata: Metadata,
 }
 
@@ -64,38 +73,308 @@ pub enum TransactionMode {
     Call,
 }
 
-/// Transaction data (compatible with both EIP-1559 and legacy transactions)
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
-pub struct TransactionData {
-    #[serde(rename = "type")]
-    pub tx_type: u8,
+/// How a `Deploy` transaction's contract address is derived
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum DeploymentMethod {
+    /// `keccak256(rlp([sender, nonce]))[12:]` — address depends on the
+    /// signer's nonce at broadcast time
+    Create,
+    /// `keccak256(0xff ++ deployer ++ salt ++ keccak256(init_code))[12:]` —
+    /// address is fixed the moment a salt and deployer are chosen
+    Create2,
+}
+
+/// Fields every transaction type carries, typed or not
+#[derive(Debug, Clone)]
+pub struct CommonTxFields {
     pub from: Address,
-    #[serde(skip_serializing_if = "Option::is_none")]
     pub to: Option<Address>,
     pub data: Bytes,
     pub nonce: u64,
     pub chain_id: u64,
-    #[serde(with = "u256_hex")]
     pub gas_limit: U256,
+    pub value: Option<U256>,
+}
 
-    // EIP-1559 fields
-    #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(with = "optional_u256_hex")]
-    pub max_fee_per_gas: Option<U256>,
+/// A single entry of an EIP-2930 access list: an address plus the storage
+/// slots the transaction pre-declares it will touch.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccessListItem {
+    pub address: Address,
+    pub storage_keys: Vec<B256>,
+}
+
+/// A fully-typed transaction (EIP-2718). Each variant only carries the
+/// fields that type legally allows, so a legacy transaction can't silently
+/// carry a `max_fee_per_gas`, for instance.
+#[derive(Debug, Clone)]
+pub enum TypedTransaction {
+    Legacy {
+        common: CommonTxFields,
+        gas_price: U256,
+    },
+    Eip2930 {
+        common: CommonTxFields,
+        gas_price: U256,
+        access_list: Vec<AccessListItem>,
+    },
+    Eip1559 {
+        common: CommonTxFields,
+        max_fee_per_gas: U256,
+        max_priority_fee_per_gas: U256,
+        access_list: Vec<AccessListItem>,
+    },
+    // EIP-4844 blob transactions: only deserializing a type-3 wire blob
+    // builds this variant, and `crypto::sign_transaction` rejects it.
+    Eip4844 {
+        common: CommonTxFields,
+        max_fee_per_gas: U256,
+        max_priority_fee_per_gas: U256,
+        max_fee_per_blob_gas: U256,
+        blob_versioned_hashes: Vec<B256>,
+    },
+}
+
+impl TypedTransaction {
+    /// The EIP-2718 type byte for this variant
+    pub fn tx_type(&self) -> u8 {
+        match self {
+            TypedTransaction::Legacy { .. } => 0,
+            TypedTransaction::Eip2930 { .. } => 1,
+            TypedTransaction::Eip1559 { .. } => 2,
+            TypedTransaction::Eip4844 { .. } => 3,
+        }
+    }
+
+    pub fn common(&self) -> &CommonTxFields {
+        match self {
+            TypedTransaction::Legacy { common, .. }
+            | TypedTransaction::Eip2930 { common, .. }
+            | TypedTransaction::Eip1559 { common, .. }
+            | TypedTransaction::Eip4844 { common, .. } => common,
+        }
+    }
+
+    pub fn access_list(&self) -> Option<&[AccessListItem]> {
+        match self {
+            TypedTransaction::Eip2930 { access_list, .. }
+            | TypedTransaction::Eip1559 { access_list, .. } => Some(access_list),
+            TypedTransaction::Legacy { .. } | TypedTransaction::Eip4844 { .. } => None,
+        }
+    }
+}
+
+// `TypedTransaction` serializes/deserializes through this flat, camelCase
+// wire struct so existing tx-params.json / signed-tx.json files keep
+// round-tripping even though the in-memory shape is now a tagged enum.
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct TransactionWire {
+    #[serde(rename = "type")]
+    tx_type: u8,
+    from: Address,
     #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(with = "optional_u256_hex")]
-    pub max_priority_fee_per_gas: Option<U256>,
+    to: Option<Address>,
+    data: Bytes,
+    nonce: u64,
+    chain_id: u64,
+    #[serde(with = "u256_hex")]
+    gas_limit: U256,
+
+    #[serde(skip_serializing_if = "Option::is_none", with = "optional_u256_hex")]
+    max_fee_per_gas: Option<U256>,
+    #[serde(skip_serializing_if = "Option::is_none", with = "optional_u256_hex")]
+    max_priority_fee_per_gas: Option<U256>,
+
+    #[serde(skip_serializing_if = "Option::is_none", with = "optional_u256_hex")]
+    gas_price: Option<U256>,
+
+    #[serde(skip_serializing_if = "Option::is_none", with = "optional_u256_hex")]
+    value: Option<U256>,
 
-    // Legacy field
     #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(with = "optional_u256_hex")]
-    pub gas_price: Option<U256>,
+    access_list: Option<Vec<AccessListItem>>,
 
-    // Optional value for payable functions
+    #[serde(skip_serializing_if = "Option::is_none", with = "optional_u256_hex")]
+    max_fee_per_blob_gas: Option<U256>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(with = "optional_u256_hex")]
-    pub value: Option<U256>,
+    blob_versioned_hashes: Option<Vec<B256>>,
+}
+
+impl Serialize for TypedTransaction {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let common = self.common().clone();
+        let wire = match self {
+            TypedTransaction::Legacy { gas_price, .. } => TransactionWire {
+                tx_type: 0,
+                from: common.from,
+                to: common.to,
+                data: common.data,
+                nonce: common.nonce,
+                chain_id: common.chain_id,
+                gas_limit: common.gas_limit,
+                max_fee_per_gas: None,
+                max_priority_fee_per_gas: None,
+                gas_price: Some(*gas_price),
+                value: common.value,
+                access_list: None,
+                max_fee_per_blob_gas: None,
+                blob_versioned_hashes: None,
+            },
+            TypedTransaction::Eip2930 {
+                gas_price,
+                access_list,
+                ..
+            } => TransactionWire {
+                tx_type: 1,
+                from: common.from,
+                to: common.to,
+                data: common.data,
+                nonce: common.nonce,
+                chain_id: common.chain_id,
+                gas_limit: common.gas_limit,
+                max_fee_per_gas: None,
+                max_priority_fee_per_gas: None,
+                gas_price: Some(*gas_price),
+                value: common.value,
+                access_list: Some(access_list.clone()),
+                max_fee_per_blob_gas: None,
+                blob_versioned_hashes: None,
+            },
+            TypedTransaction::Eip1559 {
+                max_fee_per_gas,
+                max_priority_fee_per_gas,
+                access_list,
+                ..
+            } => TransactionWire {
+                tx_type: 2,
+                from: common.from,
+                to: common.to,
+                data: common.data,
+                nonce: common.nonce,
+                chain_id: common.chain_id,
+                gas_limit: common.gas_limit,
+                max_fee_per_gas: Some(*max_fee_per_gas),
+                max_priority_fee_per_gas: Some(*max_priority_fee_per_gas),
+                gas_price: None,
+                value: common.value,
+                access_list: if access_list.is_empty() {
+                    None
+                } else {
+                    Some(access_list.clone())
+                },
+                max_fee_per_blob_gas: None,
+                blob_versioned_hashes: None,
+            },
+            TypedTransaction::Eip4844 {
+                max_fee_per_gas,
+                max_priority_fee_per_gas,
+                max_fee_per_blob_gas,
+                blob_versioned_hashes,
+                ..
+            } => TransactionWire {
+                tx_type: 3,
+                from: common.from,
+                to: common.to,
+                data: common.data,
+                nonce: common.nonce,
+                chain_id: common.chain_id,
+                gas_limit: common.gas_limit,
+                max_fee_per_gas: Some(*max_fee_per_gas),
+                max_priority_fee_per_gas: Some(*max_priority_fee_per_gas),
+                gas_price: None,
+                value: common.value,
+                access_list: None,
+                max_fee_per_blob_gas: Some(*max_fee_per_blob_gas),
+                blob_versioned_hashes: Some(blob_versioned_hashes.clone()),
+            },
+        };
+        wire.serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for TypedTransaction {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let wire = TransactionWire::deserialize(deserializer)?;
+        let common = CommonTxFields {
+            from: wire.from,
+            to: wire.to,
+            data: wire.data,
+            nonce: wire.nonce,
+            chain_id: wire.chain_id,
+            gas_limit: wire.gas_limit,
+            value: wire.value,
+        };
+
+        // Reject fee fields that don't belong to the declared type up front,
+        // rather than silently dropping them — a `tx_type: 0` wire blob that
+        // also carries `maxFeePerGas` is almost certainly a caller bug.
+        if wire.tx_type == 0 || wire.tx_type == 1 {
+            if wire.max_fee_per_gas.is_some() || wire.max_priority_fee_per_gas.is_some() {
+                return Err(serde::de::Error::custom(format!(
+                    "transaction type {} must not set maxFeePerGas/maxPriorityFeePerGas",
+                    wire.tx_type
+                )));
+            }
+        } else if wire.gas_price.is_some() {
+            return Err(serde::de::Error::custom(format!(
+                "transaction type {} must not set gasPrice",
+                wire.tx_type
+            )));
+        }
+
+        Ok(match wire.tx_type {
+            0 => TypedTransaction::Legacy {
+                common,
+                gas_price: wire
+                    .gas_price
+                    .ok_or_else(|| serde::de::Error::missing_field("gasPrice"))?,
+            },
+            1 => TypedTransaction::Eip2930 {
+                common,
+                gas_price: wire
+                    .gas_price
+                    .ok_or_else(|| serde::de::Error::missing_field("gasPrice"))?,
+                access_list: wire.access_list.unwrap_or_default(),
+            },
+            2 => TypedTransaction::Eip1559 {
+                common,
+                max_fee_per_gas: wire
+                    .max_fee_per_gas
+                    .ok_or_else(|| serde::de::Error::missing_field("maxFeePerGas"))?,
+                max_priority_fee_per_gas: wire
+                    .max_priority_fee_per_gas
+                    .ok_or_else(|| serde::de::Error::missing_field("maxPriorityFeePerGas"))?,
+                access_list: wire.access_list.unwrap_or_default(),
+            },
+            3 => TypedTransaction::Eip4844 {
+                common,
+                max_fee_per_gas: wire
+                    .max_fee_per_gas
+                    .ok_or_else(|| serde::de::Error::missing_field("maxFeePerGas"))?,
+                max_priority_fee_per_gas: wire
+                    .max_priority_fee_per_gas
+                    .ok_or_else(|| serde::de::Error::missing_field("maxPriorityFeePerGas"))?,
+                max_fee_per_blob_gas: wire
+                    .max_fee_per_blob_gas
+                    .ok_or_else(|| serde::de::Error::missing_field("maxFeePerBlobGas"))?,
+                blob_versioned_hashes: wire.blob_versioned_hashes.unwrap_or_default(),
+            },
+            other => {
+                return Err(serde::de::Error::custom(format!(
+                    "unsupported transaction type: {other}"
+                )))
+            }
+        })
+    }
 }
 
 /// Metadata about the transaction
@@ -109,6 +388,49 @@ pub struct Metadata {
     pub signed: bool,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub signed_at: Option<String>,
+    /// Fees suggested from `eth_feeHistory`, kept alongside the fees actually
+    /// chosen for the transaction so an offline signer can sanity-check them
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub suggested_fees: Option<SuggestedFees>,
+    /// Deterministic CREATE2 address, computed during `prepare` (before the
+    /// transaction is ever signed) when a `--salt` was supplied for deploy
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub predicted_contract_address: Option<Address>,
+    /// Which address-derivation rule produced `predicted_contract_address`;
+    /// `prepare` sets it only for CREATE2. A plain CREATE deploy leaves it
+    /// unset here, and its rule is recorded on `SignedTx::deployment_method`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deployment_method: Option<DeploymentMethod>,
+    /// RPC endpoints that independently agreed on chain ID, nonce, and (if
+    /// applicable) contract bytecode hash, when `--verify-rpc` was used
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub verified_rpc_endpoints: Option<Vec<String>>,
+    /// ERC-20 `Transfer` event this transaction should emit, recorded for a
+    /// token deposit so `broadcast --expect-transfer` can confirm it
+    /// actually happened, not just that the transaction didn't revert
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expected_transfer: Option<ExpectedTransfer>,
+}
+
+/// An ERC-20 `Transfer(address indexed from, address indexed to, uint256
+/// value)` log a transaction is expected to emit
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExpectedTransfer {
+    pub token: Address,
+    pub to: Address,
+    #[serde(with = "u256_hex")]
+    pub value: U256,
+}
+
+/// A fee suggestion derived from recent `eth_feeHistory` data
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SuggestedFees {
+    #[serde(with = "u256_hex")]
+    pub max_fee_per_gas: U256,
+    #[serde(with = "u256_hex")]
+    pub max_priority_fee_per_gas: U256,
 }
 
 /// Signed transaction ready for broadcasting
@@ -123,10 +445,24 @@ pub struct SignedTx {
     pub from: Address,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub predicted_contract_address: Option<Address>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deployment_method: Option<DeploymentMethod>,
     pub metadata: Metadata,
 }
 
-/// Transaction receipt after broadcasting
+/// Fields recovered by independently decoding a signed transaction envelope,
+/// for comparison against what the `SignedTx` claims
+#[derive(Debug, Clone)]
+pub struct VerifiedTx {
+    pub from: Address,
+    pub to: Option<Address>,
+    pub value: U256,
+    pub nonce: u64,
+    pub chain_id: u64,
+    pub gas_limit: u64,
+}
+
+/// Typed transaction receipt after broadcasting
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct TxReceipt {
@@ -138,17 +474,41 @@ pub struct TxReceipt {
     pub block_hash: String,
     pub from: Address,
     pub to: Option<Address>,
+    /// EIP-2718 type byte of the mined transaction (0 = legacy, 1 = EIP-2930, 2 = EIP-1559, ...)
+    #[serde(rename = "type")]
+    pub tx_type: u8,
     #[serde(rename = "gasUsed")]
     pub gas_used: String,
+    #[serde(rename = "cumulativeGasUsed")]
+    pub cumulative_gas_used: String,
+    #[serde(rename = "effectiveGasPrice")]
+    pub effective_gas_price: String,
+    #[serde(rename = "logsBloom")]
+    pub logs_bloom: String,
     pub status: u64,
+    /// Decoded `Error(string)`/`Panic(uint256)` revert reason, populated when `status == 0`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub revert_reason: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(rename = "contractAddress")]
     pub contract_address: Option<Address>,
+    #[serde(default)]
+    pub logs: Vec<LogEntry>,
     pub metadata: HashMap<String, serde_json::Value>,
 }
 
+/// A single raw log entry (emitting address, topics, data), as found in a
+/// transaction receipt or returned by `eth_getLogs`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LogEntry {
+    pub address: Address,
+    pub topics: Vec<B256>,
+    pub data: Bytes,
+}
+
 // Custom serialization for U256 as hex strings
-mod u256_hex {
+pub(crate) mod u256_hex {
     use alloy::primitives::U256;
     use serde::{Deserialize, Deserializer, Serializer};
 
@@ -0,0 +1,165 @@
+//! Encrypted mnemonic vault
+//!
+//! Seals a BIP-39 mnemonic at rest so it never sits in plaintext on a
+//! shared machine: a passphrase is stretched into a 256-bit key with
+//! Argon2id, then the mnemonic is sealed with ChaCha20-Poly1305 using a
+//! random 12-byte nonce. The salt, nonce, and Argon2id parameters all live
+//! alongside the ciphertext in a small versioned JSON file; only the
+//! passphrase can reconstruct the key.
+
+use crate::Result;
+use argon2::{Algorithm, Argon2, Params, Version};
+use chacha20poly1305::{
+    aead::{Aead, KeyInit},
+    ChaCha20Poly1305, Key, Nonce,
+};
+use rand::RngCore;
+use serde::{Deserialize, Serialize};
+
+const SALT_LEN: usize = 16;
+const NONCE_LEN: usize = 12;
+const KEY_LEN: usize = 32;
+const TAG_LEN: usize = 16;
+const CURRENT_VERSION: u8 = 1;
+
+/// Argon2id parameters recorded alongside the ciphertext, so a vault still
+/// decrypts correctly even if the defaults below change in a later version.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Argon2Params {
+    pub m_cost: u32,
+    pub t_cost: u32,
+    pub p_cost: u32,
+}
+
+impl Default for Argon2Params {
+    fn default() -> Self {
+        // OWASP-recommended Argon2id minimums: 19 MiB, 2 iterations, 1 lane.
+        Self {
+            m_cost: 19 * 1024,
+            t_cost: 2,
+            p_cost: 1,
+        }
+    }
+}
+
+/// A passphrase-sealed mnemonic: `ChaCha20-Poly1305(key, nonce, mnemonic)`
+/// where `key = Argon2id(passphrase, salt, argon2_params)`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VaultFile {
+    pub version: u8,
+    pub argon2_params: Argon2Params,
+    #[serde(with = "hex_bytes")]
+    pub salt: Vec<u8>,
+    #[serde(with = "hex_bytes")]
+    pub nonce: Vec<u8>,
+    #[serde(with = "hex_bytes")]
+    pub ciphertext: Vec<u8>,
+    #[serde(with = "hex_bytes")]
+    pub tag: Vec<u8>,
+}
+
+/// Derive a 32-byte key from `passphrase` and `salt` with Argon2id.
+fn derive_key(passphrase: &str, salt: &[u8], params: &Argon2Params) -> Result<[u8; KEY_LEN]> {
+    let argon2_params = Params::new(params.m_cost, params.t_cost, params.p_cost, Some(KEY_LEN))
+        .map_err(|e| eyre::eyre!("Invalid Argon2id parameters: {}", e))?;
+    let argon2 = Argon2::new(Algorithm::Argon2id, Version::V0x13, argon2_params);
+
+    let mut key = [0u8; KEY_LEN];
+    argon2
+        .hash_password_into(passphrase.as_bytes(), salt, &mut key)
+        .map_err(|e| eyre::eyre!("Argon2id key derivation failed: {}", e))?;
+    Ok(key)
+}
+
+/// Seal `mnemonic` with `passphrase` into a [`VaultFile`].
+pub fn seal(mnemonic: &str, passphrase: &str) -> Result<VaultFile> {
+    let argon2_params = Argon2Params::default();
+
+    let mut salt = [0u8; SALT_LEN];
+    rand::thread_rng().fill_bytes(&mut salt);
+    let mut nonce_bytes = [0u8; NONCE_LEN];
+    rand::thread_rng().fill_bytes(&mut nonce_bytes);
+
+    let key = derive_key(passphrase, &salt, &argon2_params)?;
+    let cipher = ChaCha20Poly1305::new(Key::from_slice(&key));
+    let nonce = Nonce::from_slice(&nonce_bytes);
+
+    let mut sealed = cipher
+        .encrypt(nonce, mnemonic.as_bytes())
+        .map_err(|e| eyre::eyre!("Failed to seal mnemonic: {}", e))?;
+    // `encrypt` appends the 16-byte Poly1305 tag to the ciphertext; split it
+    // back out so the file keeps {salt, nonce, ciphertext, tag} as distinct
+    // fields.
+    let tag = sealed.split_off(sealed.len() - TAG_LEN);
+
+    Ok(VaultFile {
+        version: CURRENT_VERSION,
+        argon2_params,
+        salt: salt.to_vec(),
+        nonce: nonce_bytes.to_vec(),
+        ciphertext: sealed,
+        tag,
+    })
+}
+
+/// Open a [`VaultFile`] with `passphrase`, returning the plaintext mnemonic.
+/// Fails rather than returning garbage on a wrong passphrase, since
+/// ChaCha20-Poly1305 is an AEAD and the tag won't verify.
+pub fn open(vault: &VaultFile, passphrase: &str) -> Result<String> {
+    if vault.version != CURRENT_VERSION {
+        return Err(eyre::eyre!("Unsupported mnemonic vault version {}", vault.version));
+    }
+
+    let key = derive_key(passphrase, &vault.salt, &vault.argon2_params)?;
+    let cipher = ChaCha20Poly1305::new(Key::from_slice(&key));
+    let nonce = Nonce::from_slice(&vault.nonce);
+
+    let mut sealed = vault.ciphertext.clone();
+    sealed.extend_from_slice(&vault.tag);
+
+    let plaintext = cipher
+        .decrypt(nonce, sealed.as_ref())
+        .map_err(|_| eyre::eyre!("Failed to decrypt mnemonic vault: wrong passphrase or corrupted file"))?;
+
+    String::from_utf8(plaintext).map_err(|e| eyre::eyre!("Decrypted vault was not valid UTF-8: {}", e))
+}
+
+/// Decrypt the vault at `path` and derive the Ethereum private key at
+/// `index`, for `sign` to use transparently in place of `PRIVATE_KEY`
+/// without the mnemonic or derived key ever touching disk.
+pub fn private_key_from_file(path: &str, passphrase: &str, index: u32) -> Result<String> {
+    let json = std::fs::read_to_string(path)
+        .map_err(|e| eyre::eyre!("Failed to read mnemonic vault {}: {}", path, e))?;
+    let vault: VaultFile = serde_json::from_str(&json)?;
+    let mnemonic = open(&vault, passphrase)?;
+
+    let signer = alloy_signer_local::MnemonicBuilder::<alloy_signer_local::coins_bip39::English>::default()
+        .phrase(mnemonic.as_str())
+        .index(index)?
+        .build()
+        .map_err(|e| eyre::eyre!("Failed to derive key from vault mnemonic: {}", e))?;
+
+    Ok(format!("0x{}", hex::encode(signer.to_bytes())))
+}
+
+// Custom serialization for raw byte fields as hex strings
+mod hex_bytes {
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S>(value: &[u8], serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&hex::encode(value))
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Vec<u8>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        hex::decode(s).map_err(serde::de::Error::custom)
+    }
+}